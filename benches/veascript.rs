@@ -0,0 +1,122 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use veascript::build_veascript;
+use veascript::structs::{BuildOptions, CompiledScript, VeaScriptParser};
+
+/// Create a script containing an embed with every component set, and the maximum number of fields.
+fn large_embed() -> String {
+    // Create fields
+    let fields: String = (0..25)
+        .map(|i| {
+            format!(
+                "#field {{ #name: \"Field {}\", #value: \"{}\", #inline: true, }}\n",
+                i,
+                "Field value ".repeat(10)
+            )
+        })
+        .collect();
+
+    // Return embed script
+    format!(
+        "#embed {{
+            #title: \"{}\",
+            #description: \"{}\",
+            #colour: #12345,
+            #image: \"https://example.com/image.png\",
+            #thumbnail: \"https://example.com/thumbnail.png\",
+            #url: \"https://example.com\",
+            #author {{ #name: \"Author\", #url: \"https://example.com\", #icon_url: \"https://example.com/icon.png\", }}
+            #footer {{ #text: \"Footer\", #icon_url: \"https://example.com/icon.png\", }}
+            #timestamp: 1700000000,
+            #fields {{ {} }}
+        }}",
+        "Title ".repeat(40),
+        "Description ".repeat(200),
+        fields
+    )
+}
+
+/// Create a script containing a deeply nested math expression.
+fn deep_math() -> String {
+    // Create nested expression
+    let depth = 60;
+    let expression = format!("{}1{}", "(2 * -".repeat(depth), " + 3)".repeat(depth));
+
+    // Return math script, along with a long flat expression
+    format!(
+        "#math {{ {} }}\n#math {{ {} }}",
+        expression,
+        vec!["1 + 2 * 3 - 4 / 5"; 500].join(" + ")
+    )
+}
+
+/// Create a script containing text and random blocks, close to the message content limit.
+fn long_text() -> String {
+    // Create random choices
+    let choices: Vec<String> = (0..100).map(|i| format!("\"Choice {}\"", i)).collect();
+
+    // Return text script
+    format!(
+        "#text {{{}}}\n#random {{ {} }}",
+        "Some long text with an escape\\n and \\u0041 unicode. ".repeat(35),
+        choices.join(", ")
+    )
+}
+
+/// Benchmark parsing and rendering a script.
+fn bench_script(c: &mut Criterion, name: &str, src: &str) {
+    // Create parser
+    let parser = VeaScriptParser::default();
+
+    // Benchmark constructing a parser
+    c.bench_function(&format!("{}/construct", name), |b| {
+        b.iter(VeaScriptParser::default)
+    });
+
+    // Benchmark parsing
+    c.bench_function(&format!("{}/parse", name), |b| {
+        b.iter(|| parser.parse(0, black_box(src)).unwrap())
+    });
+
+    // Benchmark building
+    let script = parser.parse(0, src).unwrap();
+    let options = BuildOptions::new().seed(0);
+
+    c.bench_function(&format!("{}/build", name), |b| {
+        b.iter(|| build_veascript(black_box(&script), &options).unwrap())
+    });
+
+    // Benchmark rendering a compiled script from its binary form
+    let bytes = CompiledScript::compile(&parser, 0, src, &options)
+        .unwrap()
+        .to_bytes()
+        .unwrap();
+
+    c.bench_function(&format!("{}/render_compiled", name), |b| {
+        b.iter(|| {
+            CompiledScript::from_bytes(black_box(&bytes))
+                .unwrap()
+                .render(&options)
+                .unwrap()
+        })
+    });
+}
+
+fn large_embed_benchmark(c: &mut Criterion) {
+    bench_script(c, "large_embed", &large_embed());
+}
+
+fn deep_math_benchmark(c: &mut Criterion) {
+    bench_script(c, "deep_math", &deep_math());
+}
+
+fn long_text_benchmark(c: &mut Criterion) {
+    bench_script(c, "long_text", &long_text());
+}
+
+criterion_group!(
+    benches,
+    large_embed_benchmark,
+    deep_math_benchmark,
+    long_text_benchmark
+);
+criterion_main!(benches);
@@ -0,0 +1,153 @@
+use crate::builders::embed::{check_duplicate, check_length};
+use crate::enums::AttachmentComponent;
+use crate::helpers::{assets, validation};
+use crate::structs::{BuildError, BuildOptions, BuildWarning, Span, Spanned, StoredAttachment};
+use std::path::Path;
+
+/// Build a stored attachment struct using a slice of VeaScript attachment components.
+pub fn build_attachment(
+    components: &[Spanned<AttachmentComponent>],
+    attachment_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredAttachment, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create attachment parts
+    let mut filename: Option<String> = None;
+    let mut data: Option<Vec<u8>> = None;
+    let mut file_name: Option<String> = None;
+    let mut description: Option<String> = None;
+    let mut spoiler = false;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            AttachmentComponent::Filename(name) => {
+                // Check if file name has already been set
+                check_duplicate(
+                    filename.is_some(),
+                    "You can only have one file name for an attachment.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the file name is valid
+                validation::filename(name).map_err(|error| BuildError::new(span, error))?;
+
+                // Set file name
+                filename = Some(name.clone());
+            }
+            AttachmentComponent::File(file) => {
+                // Check if contents have already been set
+                if data.is_some() {
+                    return Err(BuildError::new(
+                        span,
+                        "An attachment can only have one #file or #content.",
+                    ));
+                }
+
+                // Get asset directory
+                let directory = options.assets.as_ref().ok_or_else(|| {
+                    BuildError::new(
+                        span,
+                        "Files can't be attached here. Use #content to attach text instead.",
+                    )
+                })?;
+
+                // Read file from the asset directory
+                let contents = assets::read(directory, file, limits.attachment_size)
+                    .map_err(|error| BuildError::new(span, error))?;
+
+                // Set contents, remembering the file's name as a default file name
+                data = Some(contents);
+                file_name = Path::new(file)
+                    .file_name()
+                    .map(|name| name.to_string_lossy().to_string());
+            }
+            AttachmentComponent::Content(content) => {
+                // Check if contents have already been set
+                if data.is_some() {
+                    return Err(BuildError::new(
+                        span,
+                        "An attachment can only have one #file or #content.",
+                    ));
+                }
+
+                // Check if the content fits within the attachment size limit
+                if content.len() > limits.attachment_size {
+                    return Err(BuildError::new(
+                        span,
+                        format!(
+                            "Your attachment content ({} bytes) is above the maximum attachment size of {} bytes.",
+                            content.len(),
+                            limits.attachment_size
+                        ),
+                    ));
+                }
+
+                // Set contents
+                data = Some(content.clone().into_bytes());
+            }
+            AttachmentComponent::Description(text) => {
+                // Check if description has already been set
+                check_duplicate(
+                    description.is_some(),
+                    "You can only have one description for an attachment.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set description
+                description = Some(check_length(
+                    text,
+                    "attachment description",
+                    limits.attachment_description,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            AttachmentComponent::Spoiler(value) => {
+                // Set spoiler
+                spoiler = *value;
+            }
+            AttachmentComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error.clone()));
+            }
+        }
+    }
+
+    // Check that the attachment has contents
+    let data = data.ok_or_else(|| {
+        BuildError::new(
+            attachment_span,
+            "An attachment needs either a #file or #content.",
+        )
+    })?;
+
+    // Get file name, falling back to the attached file's name
+    let filename = filename.or(file_name).ok_or_else(|| {
+        BuildError::new(
+            attachment_span,
+            "An attachment with #content needs a #filename.",
+        )
+    })?;
+
+    // Create attachment
+    let mut attachment = StoredAttachment::new(filename, data).spoiler(spoiler);
+
+    if let Some(description) = description {
+        attachment = attachment.description(description);
+    }
+
+    // Return attachment
+    Ok(attachment)
+}
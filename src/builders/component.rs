@@ -0,0 +1,612 @@
+use crate::builders::embed::{check_duplicate, check_length};
+use crate::enums::{
+    ActionRowComponent, ButtonComponent, ButtonStyle, SelectComponent, SelectKind,
+    SelectOptionComponent,
+};
+use crate::helpers::{suggestions, validation};
+use crate::structs::{
+    BuildError, BuildOptions, BuildWarning, ComponentRoute, Span, Spanned, StoredActionRow,
+    StoredButton, StoredSelect, StoredSelectOption, MAX_SELECT_VALUES,
+};
+use serenity::all::ReactionType;
+
+/// The error for an action row that has both buttons and select menus, or several select menus.
+const MIXED_ROW_ERROR: &str =
+    "An action row can only have buttons or a single select menu. Use another #actionrow for this.";
+
+/// Build a stored action row using a slice of VeaScript action row components.
+pub fn build_action_row(
+    components: &[Spanned<ActionRowComponent>],
+    row_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredActionRow, BuildError> {
+    // Create row parts
+    let mut buttons = Vec::new();
+    let mut select: Option<StoredSelect> = None;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            ActionRowComponent::Button(components) => {
+                // Check that the row doesn't have a select menu
+                if select.is_some() {
+                    return Err(BuildError::new(span, MIXED_ROW_ERROR));
+                }
+
+                // Add button to row
+                buttons.push(build_button(components, span, options, warnings)?);
+            }
+            ActionRowComponent::Select(components) => {
+                // Check that the row doesn't have buttons or another select menu
+                if !buttons.is_empty() || select.is_some() {
+                    return Err(BuildError::new(span, MIXED_ROW_ERROR));
+                }
+
+                // Set row select menu
+                select = Some(build_select(components, span, options, warnings)?);
+            }
+            ActionRowComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Create row
+    let row = match select {
+        Some(select) => StoredActionRow::Select(select),
+        None => StoredActionRow::Buttons(buttons),
+    };
+
+    // Validate the finished row, pointing any error at the whole row
+    row.validate(&options.limits)
+        .map_err(|error| BuildError::new(row_span, error.message))?;
+
+    // Return row
+    Ok(row)
+}
+
+/// Build a stored button using a slice of VeaScript button components.
+pub fn build_button(
+    components: &[Spanned<ButtonComponent>],
+    button_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredButton, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create new stored button, with its style set after all components are read
+    let mut button = StoredButton::new(ButtonStyle::Primary);
+    let mut style: Option<ButtonStyle> = None;
+    let mut handler: Option<Spanned<String>> = None;
+    let mut state: Option<Spanned<String>> = None;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            ButtonComponent::Style(name) => {
+                // Check if style has already been set
+                check_duplicate(
+                    style.is_some(),
+                    "You can only set the style for a button once.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button style
+                style = Some(ButtonStyle::from_name(name).ok_or_else(|| {
+                    unknown_name_error(
+                        span,
+                        "button style",
+                        name,
+                        ButtonStyle::ALL.map(|style| style.name()),
+                    )
+                })?);
+            }
+            ButtonComponent::Label(label) => {
+                // Check if label has already been set
+                check_duplicate(
+                    button.label.is_some(),
+                    "You can only have one label for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button label
+                button.label = Some(check_length(
+                    label,
+                    "button label",
+                    limits.button_label,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            ButtonComponent::Emoji(emoji) => {
+                // Check if emoji has already been set
+                check_duplicate(
+                    button.emoji.is_some(),
+                    "You can only have one emoji for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button emoji
+                check_emoji(emoji, span)?;
+                button.emoji = Some(emoji.clone());
+            }
+            ButtonComponent::CustomId(custom_id) => {
+                // Check if custom id has already been set
+                check_duplicate(
+                    button.custom_id.is_some(),
+                    "You can only have one custom id for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button custom id
+                button.custom_id = Some(custom_id.clone());
+            }
+            ButtonComponent::Url(url) => {
+                // Check if the url is valid
+                validation::url(url, "button URL").map_err(|error| BuildError::new(span, error))?;
+
+                // Check if url has already been set
+                check_duplicate(
+                    button.url.is_some(),
+                    "You can only have one url for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button url
+                button.url = Some(url.clone());
+            }
+            ButtonComponent::Disabled(disabled) => {
+                // Set button disabled state
+                button.disabled = *disabled;
+            }
+            ButtonComponent::Handler(name) => {
+                // Check if handler has already been set
+                check_duplicate(
+                    handler.is_some(),
+                    "You can only have one handler for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button handler
+                handler = Some(Spanned::new(name.clone(), span));
+            }
+            ButtonComponent::State(value) => {
+                // Check if state has already been set
+                check_duplicate(
+                    state.is_some(),
+                    "You can only have one state for a button.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set button state
+                state = Some(Spanned::new(value.clone(), span));
+            }
+            ButtonComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Set style, making buttons with a url into links by default
+    button.style = style.unwrap_or(if button.url.is_some() {
+        ButtonStyle::Link
+    } else {
+        ButtonStyle::Primary
+    });
+
+    // Route the button to its handler
+    if let Some(route) = route_custom_id(handler, state, &button.custom_id, options)? {
+        if button.style == ButtonStyle::Link {
+            return Err(BuildError::new(
+                button_span,
+                "Link buttons open their url, so they can't have a #handler.",
+            ));
+        }
+
+        button.custom_id = Some(route);
+    }
+
+    // Validate the finished button, pointing any error at the whole button
+    button
+        .validate(limits)
+        .map_err(|error| BuildError::new(button_span, error.message))?;
+
+    // Return button
+    Ok(button)
+}
+
+/// Build a stored select menu using a slice of VeaScript select menu components.
+pub fn build_select(
+    components: &[Spanned<SelectComponent>],
+    select_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredSelect, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create new stored select menu, with its kind and custom id set after all components are read
+    let mut select = StoredSelect::new(SelectKind::String, "");
+    let mut kind: Option<SelectKind> = None;
+    let mut custom_id: Option<String> = None;
+    let mut handler: Option<Spanned<String>> = None;
+    let mut state: Option<Spanned<String>> = None;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            SelectComponent::Kind(name) => {
+                // Check if kind has already been set
+                check_duplicate(
+                    kind.is_some(),
+                    "You can only set the type of a select menu once.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set select menu kind
+                kind = Some(SelectKind::from_name(name).ok_or_else(|| {
+                    unknown_name_error(
+                        span,
+                        "select menu type",
+                        name,
+                        SelectKind::ALL.map(|kind| kind.name()),
+                    )
+                })?);
+            }
+            SelectComponent::CustomId(id) => {
+                // Check if custom id has already been set
+                check_duplicate(
+                    custom_id.is_some(),
+                    "You can only have one custom id for a select menu.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set select menu custom id
+                custom_id = Some(id.clone());
+            }
+            SelectComponent::Placeholder(placeholder) => {
+                // Check if placeholder has already been set
+                check_duplicate(
+                    select.placeholder.is_some(),
+                    "You can only have one placeholder for a select menu.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set select menu placeholder
+                select.placeholder = Some(check_length(
+                    placeholder,
+                    "select menu placeholder",
+                    limits.select_placeholder,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            SelectComponent::MinValues(min_values) => {
+                // Set minimum number of values
+                select.min_values = Some(value_count(*min_values, "#min_values", span)?);
+            }
+            SelectComponent::MaxValues(max_values) => {
+                // Set maximum number of values
+                select.max_values = Some(value_count(*max_values, "#max_values", span)?);
+            }
+            SelectComponent::Disabled(disabled) => {
+                // Set select menu disabled state
+                select.disabled = *disabled;
+            }
+            SelectComponent::Option(components) => {
+                // Check if option limit has been exceeded
+                if select.options.len() >= limits.select_options {
+                    return Err(BuildError::new(
+                        span,
+                        format!(
+                            "A select menu can only have a maximum of {} options.",
+                            limits.select_options
+                        ),
+                    ));
+                }
+
+                // Add option to select menu
+                let option = build_select_option(components, span, options, warnings)?;
+                select.options.push(option);
+            }
+            SelectComponent::Handler(name) => {
+                // Check if handler has already been set
+                check_duplicate(
+                    handler.is_some(),
+                    "You can only have one handler for a select menu.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set select menu handler
+                handler = Some(Spanned::new(name.clone(), span));
+            }
+            SelectComponent::State(value) => {
+                // Check if state has already been set
+                check_duplicate(
+                    state.is_some(),
+                    "You can only have one state for a select menu.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set select menu state
+                state = Some(Spanned::new(value.clone(), span));
+            }
+            SelectComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Set kind and custom id, routing the select menu to its handler if it has one
+    select.kind = kind.unwrap_or_default();
+    select.custom_id = route_custom_id(handler, state, &custom_id, options)?
+        .or(custom_id)
+        .ok_or_else(|| {
+            BuildError::new(
+                select_span,
+                "A select menu needs a #custom_id or a #handler.",
+            )
+        })?;
+
+    // Validate the finished select menu, pointing any error at the whole select menu
+    select
+        .validate(limits)
+        .map_err(|error| BuildError::new(select_span, error.message))?;
+
+    // Return select menu
+    Ok(select)
+}
+
+/// Build a stored select menu option using a slice of VeaScript option components.
+pub fn build_select_option(
+    components: &[Spanned<SelectOptionComponent>],
+    option_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredSelectOption, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create new stored option
+    let mut option = StoredSelectOption::new("", "");
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            SelectOptionComponent::Label(label) => {
+                // Check if label has already been set
+                check_duplicate(
+                    !option.label.is_empty(),
+                    "You can only have one label for an option.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set option label
+                option.label = check_length(
+                    label,
+                    "option label",
+                    limits.select_option_text,
+                    span,
+                    options,
+                    warnings,
+                )?;
+            }
+            SelectOptionComponent::Value(value) => {
+                // Check if value has already been set
+                check_duplicate(
+                    !option.value.is_empty(),
+                    "You can only have one value for an option.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set option value
+                option.value = value.clone();
+            }
+            SelectOptionComponent::Description(description) => {
+                // Check if description has already been set
+                check_duplicate(
+                    option.description.is_some(),
+                    "You can only have one description for an option.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set option description
+                option.description = Some(check_length(
+                    description,
+                    "option description",
+                    limits.select_option_text,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            SelectOptionComponent::Emoji(emoji) => {
+                // Check if emoji has already been set
+                check_duplicate(
+                    option.emoji.is_some(),
+                    "You can only have one emoji for an option.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set option emoji
+                check_emoji(emoji, span)?;
+                option.emoji = Some(emoji.clone());
+            }
+            SelectOptionComponent::Default(default) => {
+                // Set whether the option is chosen by default
+                option.default = *default;
+            }
+            SelectOptionComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Validate the finished option, pointing any error at the whole option
+    option
+        .validate(limits)
+        .map_err(|error| BuildError::new(option_span, error.message))?;
+
+    // Return option
+    Ok(option)
+}
+
+/// Create the custom id that routes a component to its handler, if it has one.
+fn route_custom_id(
+    handler: Option<Spanned<String>>,
+    state: Option<Spanned<String>>,
+    custom_id: &Option<String>,
+    options: &BuildOptions,
+) -> Result<Option<String>, BuildError> {
+    // Check that state is only used with a handler
+    let Some(handler) = handler else {
+        return match state {
+            Some(state) => Err(BuildError::new(
+                state.span,
+                "A #state can only be used with a #handler.",
+            )),
+            None => Ok(None),
+        };
+    };
+
+    // Check that the component doesn't already have a custom id
+    if custom_id.is_some() {
+        return Err(BuildError::new(
+            handler.span,
+            "A component with a #handler can't also have a #custom_id.",
+        ));
+    }
+
+    // Check handler name
+    validation::handler_name(&handler.node)
+        .map_err(|error| BuildError::new(handler.span, error))?;
+
+    // Get script id
+    let script_id = options.script_id.as_ref().ok_or_else(|| {
+        BuildError::new(
+            handler.span,
+            "Components with a #handler can only be used in scripts that are built with a script id.",
+        )
+    })?;
+
+    if script_id.contains(':') {
+        return Err(BuildError::new(
+            handler.span,
+            format!("The script id {} can't contain `:`.", script_id),
+        ));
+    }
+
+    // Return custom id
+    let state = state.map(|state| state.node).unwrap_or_default();
+    Ok(Some(
+        ComponentRoute::new(script_id, handler.node, state).encode(),
+    ))
+}
+
+/// Check that an emoji is a unicode emoji or a custom emoji such as `<:name:id>`.
+pub(super) fn check_emoji(emoji: &str, span: Span) -> Result<(), BuildError> {
+    ReactionType::try_from(emoji).map(|_| ()).map_err(|_| {
+        BuildError::new(
+            span,
+            format!(
+                "Invalid emoji provided: {}. Use a unicode emoji or a custom emoji like <:name:id>.",
+                emoji
+            ),
+        )
+    })
+}
+
+/// Convert a number of select menu values, checking that it is in range.
+fn value_count(count: i32, name: &str, span: Span) -> Result<u8, BuildError> {
+    u8::try_from(count)
+        .ok()
+        .filter(|count| *count <= MAX_SELECT_VALUES)
+        .ok_or_else(|| {
+            BuildError::new(
+                span,
+                format!(
+                    "A select menu's {} must be between 0 and {}.",
+                    name, MAX_SELECT_VALUES
+                ),
+            )
+        })
+}
+
+/// Create an error for an unknown name, suggesting the closest valid name.
+fn unknown_name_error<const N: usize>(
+    span: Span,
+    kind: &str,
+    name: &str,
+    names: [&str; N],
+) -> BuildError {
+    // Create error message
+    let mut error = format!(
+        "Unknown {} `{}`. Use one of: {}.",
+        kind,
+        name,
+        names.join(", ")
+    );
+
+    // Suggest the closest name
+    if let Some(suggestion) = suggestions::closest(name, names) {
+        error = format!(
+            "Unknown {} `{}`. Did you mean `{}`?",
+            kind, name, suggestion
+        );
+    }
+
+    // Return error
+    BuildError::new(span, error)
+}
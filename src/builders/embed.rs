@@ -1,12 +1,25 @@
 use crate::enums::{
     EmbedAuthorComponent, EmbedComponent, EmbedFieldComponent, EmbedFooterComponent,
 };
-use crate::helpers::{hexadecimal, validation};
-use crate::structs::{StoredEmbed, StoredEmbedAuthor, StoredEmbedField, StoredEmbedFooter};
-use chumsky::prelude::*;
+use crate::helpers::{length, validation};
+use crate::runners;
+use crate::structs::{
+    BuildError, BuildOptions, BuildWarning, Span, Spanned, StoredEmbed, StoredEmbedAuthor,
+    StoredEmbedField, StoredEmbedFooter,
+};
+use rand::Rng;
+
+/// Build a stored embed struct using a slice of VeaScript embed components.
+pub fn build_embed(
+    components: &[Spanned<EmbedComponent>],
+    embed_span: Span,
+    rng: &mut impl Rng,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredEmbed, BuildError> {
+    // Get limits
+    let limits = &options.limits;
 
-/// Build a stored embed struct using a vector of VeaScript embed components.
-pub fn build_embed(components: Vec<EmbedComponent>) -> Result<StoredEmbed, String> {
     // Create new stored embed
     let mut embed = StoredEmbed::new();
 
@@ -15,82 +28,98 @@ pub fn build_embed(components: Vec<EmbedComponent>) -> Result<StoredEmbed, Strin
 
     // Iterate over components
     for component in components {
-        match component {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
             EmbedComponent::Author(components) => {
+                // Check if author has already been set
+                check_duplicate(
+                    embed.author.is_some(),
+                    "You can only have one author for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
                 // Add author to embed
-                embed = embed.author(build_author(components)?)
+                embed = embed.author(build_author(components, span, options, warnings)?)
             }
             EmbedComponent::Title(title) => {
-                // Get title length
-                let title_length = title.len();
-
                 // Check if title has already been set
-                if embed.title.is_some() {
-                    // Return multiple title error
-                    return Err(String::from("You can only have one title for an embed."));
-                } else if title_length > 256 {
-                    // Return length error
-                    return Err(format!(
-                        "The length of your title ({}) is above the maximum of 256 characters.",
-                        title_length
-                    ));
-                } else {
-                    // Add title to embed
-                    embed = embed.title(title);
-                }
+                check_duplicate(
+                    embed.title.is_some(),
+                    "You can only have one title for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Add title to embed
+                let title = check_length(title, "title", limits.title, span, options, warnings)?;
+                embed = embed.title(title);
             }
             EmbedComponent::Description(description) => {
-                // Get description length
-                let description_length = description.len();
-
                 // Check if description has already been set
-                if embed.description.is_some() {
-                    // Return multiple description error
-                    return Err(String::from(
-                        "You can only have one description for an embed.",
-                    ));
-                } else if description_length > 4096 {
-                    // Return length error
-                    return Err(format!(
-                        "The length of your title ({}) is above the maximum of 4096 characters.",
-                        description_length
-                    ));
-                } else {
-                    // Add description to embed
-                    embed = embed.description(description);
-                }
+                check_duplicate(
+                    embed.description.is_some(),
+                    "You can only have one description for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Add description to embed
+                let description = check_length(
+                    description,
+                    "description",
+                    limits.description,
+                    span,
+                    options,
+                    warnings,
+                )?;
+                embed = embed.description(description);
             }
             EmbedComponent::Colour(colour) => {
                 // Check if colour has already been set
-                if colour_set {
-                    // Return multiple colour error
-                    return Err(String::from(
-                        "You can only set the colour for an embed once.",
-                    ));
-                } else if colour > 0xFFFFFF {
-                    // Return length error
-                    return Err(format!(
-                        "Your colour value (#{}) is greater than the limit of #FFFFFF.",
-                        hexadecimal::hex_to_str(colour)
-                    ));
-                } else {
-                    // Set embed colour
-                    embed = embed.colour(colour);
-
-                    // Set colour set flag
-                    colour_set = true;
-                }
+                check_duplicate(
+                    colour_set,
+                    "You can only set the colour for an embed once.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set embed colour
+                embed = embed.colour(runners::eval_colour(colour, rng)?);
+
+                // Set colour set flag
+                colour_set = true;
             }
             EmbedComponent::Fields(fields) => {
                 // Build embed fields
-                let mut fields = build_fields(fields)?;
+                let mut fields = build_fields(fields, options, warnings)?;
 
                 // Check if field limit has been exceeded.
-                if embed.fields.len() + fields.len() > 25 {
-                    // Return too many fields error
-                    return Err(String::from(
-                        "An embed can only have a maximum of 25 fields.",
+                if embed.fields.len() + fields.len() > limits.fields {
+                    // Create too many fields error
+                    let error = format!(
+                        "An embed can only have a maximum of {} fields.",
+                        limits.fields
+                    );
+
+                    if !options.lenient {
+                        // Return too many fields error
+                        return Err(BuildError::new(span, error));
+                    }
+
+                    // Drop fields over the limit
+                    let kept = limits.fields.saturating_sub(embed.fields.len());
+                    warnings.push(BuildWarning::new(
+                        span,
+                        format!("{} {} fields were removed.", error, fields.len() - kept),
                     ));
+                    fields.truncate(kept);
                 }
 
                 // Add new fields to embed
@@ -98,80 +127,177 @@ pub fn build_embed(components: Vec<EmbedComponent>) -> Result<StoredEmbed, Strin
             }
             EmbedComponent::Image(image_url) => {
                 // Check if the image url is valid.
-                validation::url(&image_url, "Image URL")?;
+                validation::url(image_url, "Image URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if image has already been set
-                if embed.image_url.is_some() {
-                    // Return multiple image error
-                    return Err(String::from("You can only have one image for an embed."));
-                } else {
-                    // Set embed image
-                    embed = embed.image(image_url);
-                }
+                check_duplicate(
+                    embed.image_url.is_some(),
+                    "You can only have one image for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the image url looks like an image
+                check_image_extension(image_url, "image", span, warnings);
+
+                // Set embed image
+                embed = embed.image(image_url);
             }
             EmbedComponent::Thumbnail(thumbnail_url) => {
                 // Check if the thumbnail url is valid.
-                validation::url(&thumbnail_url, "Thumbnail URL")?;
+                validation::url(thumbnail_url, "Thumbnail URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if image has already been set
-                if embed.thumbnail_url.is_some() {
-                    // Return multiple thumbnail error
-                    return Err(String::from(
-                        "You can only have one thumbnail for an embed.",
-                    ));
-                } else {
-                    // Set embed thumbnail
-                    embed = embed.thumbnail(thumbnail_url);
-                }
+                check_duplicate(
+                    embed.thumbnail_url.is_some(),
+                    "You can only have one thumbnail for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the thumbnail url looks like an image
+                check_image_extension(thumbnail_url, "thumbnail", span, warnings);
+
+                // Set embed thumbnail
+                embed = embed.thumbnail(thumbnail_url);
             }
             EmbedComponent::Url(embed_url) => {
                 // Check if the embed url is valid.
-                validation::url(&embed_url, "Embed URL")?;
+                validation::url(embed_url, "Embed URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if url has already been set
-                if embed.url.is_some() {
-                    // Return multiple url error
-                    return Err(String::from("You can only have one url for an embed."));
-                } else {
-                    // Set embed url
-                    embed = embed.url(embed_url);
-                }
+                check_duplicate(
+                    embed.url.is_some(),
+                    "You can only have one url for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set embed url
+                embed = embed.url(embed_url);
             }
             EmbedComponent::Footer(components) => {
+                // Check if footer has already been set
+                check_duplicate(
+                    embed.footer.is_some(),
+                    "You can only have one footer for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
                 // Add footer to embed
-                embed = embed.footer(build_footer(components)?);
+                embed = embed.footer(build_footer(components, span, options, warnings)?);
             }
             EmbedComponent::Timestamp(stamp) => {
-                // Check if timestamp is not negative
-                if stamp >= 0 {
-                    // Add timestamp to embed
-                    embed = embed.timestamp(stamp);
-                } else {
-                    // Return invalid timestamp error
-                    return Err(format!("Invalid embed timestamp ({}) - the timestamp for an embed must be greater than 0.", stamp));
-                }
+                // Check if timestamp has already been set
+                check_duplicate(
+                    embed.timestamp.is_some(),
+                    "You can only have one timestamp for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if timestamp is valid
+                validation::timestamp(*stamp).map_err(|error| BuildError::new(span, error))?;
+
+                // Add timestamp to embed
+                embed = embed.timestamp(*stamp);
+            }
+            EmbedComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
             }
         }
     }
 
+    // Check if the embed has no content
+    if embed.title.is_none()
+        && embed.description.is_none()
+        && embed.author.is_none()
+        && embed.footer.is_none()
+        && embed.fields.is_empty()
+        && embed.image_url.is_none()
+        && embed.thumbnail_url.is_none()
+    {
+        warnings.push(BuildWarning::new(
+            embed_span,
+            "This embed is empty, so it won't display anything.",
+        ));
+    }
+
+    // Check if the colour was left at the default
+    if !colour_set {
+        warnings.push(BuildWarning::new(
+            embed_span,
+            "This embed has no colour set, so it will use the default colour of 0.",
+        ));
+    }
+
     // Get the total number of characters in the embed.
-    let total_chars = embed.total_chars();
-
-    // Check if embed characters doesn't exceed 6000.
-    if total_chars > 6000 {
-        // Return total chars error
-        Err(format!(
-            "Your embed has {} characters in total. An embed cannot exceed 6000 total characters.",
-            total_chars
-        ))
-    } else {
-        // Return embed
-        Ok(embed)
+    let total_chars = embed.total_length(limits.unit);
+
+    // Fit embed to the embed limit if lenient
+    if options.lenient && total_chars > limits.embed_total {
+        // Remove fields from the end until the embed fits
+        let mut removed = 0;
+        while embed.total_length(limits.unit) > limits.embed_total && embed.fields.pop().is_some() {
+            removed += 1;
+        }
+
+        // Shorten the description if the embed still doesn't fit
+        let over = embed
+            .total_length(limits.unit)
+            .saturating_sub(limits.embed_total);
+        let mut shortened = false;
+
+        if let Some(description) = embed.description.as_mut().filter(|_| over > 0) {
+            let max = limits.length(description).saturating_sub(over);
+            *description = length::truncate(description, max, limits.unit);
+            shortened = true;
+        }
+
+        // Add total chars warning
+        warnings.push(BuildWarning::new(
+            embed_span,
+            format!(
+                "Your embed has {} characters in total. An embed cannot exceed {} total characters. {} fields were removed{}.",
+                total_chars,
+                limits.embed_total,
+                removed,
+                if shortened {
+                    " and the description was shortened"
+                } else {
+                    ""
+                }
+            ),
+        ));
     }
+
+    // Validate the finished embed, pointing any error at the whole embed
+    embed.validate(limits).map_err(|errors| {
+        let error = errors.into_iter().next().unwrap();
+        BuildError::new(embed_span, error.message)
+    })?;
+
+    // Return embed
+    Ok(embed)
 }
 
-// Build a stored embed author struct using a vector of VeaScript embed author components.
-pub fn build_author(components: Vec<EmbedAuthorComponent>) -> Result<StoredEmbedAuthor, String> {
+// Build a stored embed author struct using a slice of VeaScript embed author components.
+pub fn build_author(
+    components: &[Spanned<EmbedAuthorComponent>],
+    author_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredEmbedAuthor, BuildError> {
     // Create new stored embed author
     let mut author = StoredEmbedAuthor::new("");
 
@@ -180,60 +306,74 @@ pub fn build_author(components: Vec<EmbedAuthorComponent>) -> Result<StoredEmbed
 
     // Iterate over components
     for component in components {
-        match component {
-            EmbedAuthorComponent::Name(name) => {
-                // Get author name length
-                let name_length = name.len();
+        // Get component span
+        let span = component.span;
 
+        match &component.node {
+            EmbedAuthorComponent::Name(name) => {
                 // Check if author name has already been set
-                if name_set {
-                    // Return multiple author name error
-                    return Err(String::from(
-                        "You can only have one author name for an embed.",
-                    ));
-                } else if name_length > 256 {
-                    // Return length error
-                    return Err(format!(
-                        "The length of your author name ({}) is above the maximum of 256 characters.",
-                        name_length
-                    ));
-                } else {
-                    // Add name to embed author
-                    author = author.name(name);
-
-                    // Set name set flag
-                    name_set = true;
-                }
+                check_duplicate(
+                    name_set,
+                    "You can only have one author name for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Add name to embed author
+                let name = check_length(
+                    name,
+                    "author name",
+                    options.limits.author_name,
+                    span,
+                    options,
+                    warnings,
+                )?;
+                author = author.name(name);
+
+                // Set name set flag
+                name_set = true;
             }
             EmbedAuthorComponent::Url(author_url) => {
                 // Check if the author url is valid.
-                validation::url(&author_url, "Embed Author URL")?;
+                validation::url(author_url, "Embed Author URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if url has already been set
-                if author.url.is_some() {
-                    // Return multiple url error
-                    return Err(String::from(
-                        "You can only have one url for an embed author.",
-                    ));
-                } else {
-                    // Set embed author url
-                    author = author.url(author_url);
-                }
+                check_duplicate(
+                    author.url.is_some(),
+                    "You can only have one url for an embed author.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set embed author url
+                author = author.url(author_url);
             }
             EmbedAuthorComponent::IconUrl(icon_url) => {
                 // Check if the author icon url is valid.
-                validation::url(&icon_url, "Embed Author Icon URL")?;
+                validation::url(icon_url, "Embed Author Icon URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if url has already been set
-                if author.icon_url.is_some() {
-                    // Return multiple icon url error
-                    return Err(String::from(
-                        "You can only have one icon url for an embed author.",
-                    ));
-                } else {
-                    // Set embed icon author url
-                    author = author.icon_url(icon_url);
-                }
+                check_duplicate(
+                    author.icon_url.is_some(),
+                    "You can only have one icon url for an embed author.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the icon url looks like an image
+                check_image_extension(icon_url, "author icon", span, warnings);
+
+                // Set embed icon author url
+                author = author.icon_url(icon_url);
+            }
+            EmbedAuthorComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
             }
         }
     }
@@ -241,15 +381,23 @@ pub fn build_author(components: Vec<EmbedAuthorComponent>) -> Result<StoredEmbed
     // Check if embed author has some field set
     if author.name.is_empty() && author.url.is_none() && author.icon_url.is_none() {
         // Return empty author error
-        return Err(String::from("An embed cannot have an empty author."));
+        return Err(BuildError::new(
+            author_span,
+            String::from("An embed cannot have an empty author."),
+        ));
     }
 
     // Return embed author
     Ok(author)
 }
 
-// Build a stored embed footer struct using a vector of VeaScript embed footer components.
-pub fn build_footer(components: Vec<EmbedFooterComponent>) -> Result<StoredEmbedFooter, String> {
+// Build a stored embed footer struct using a slice of VeaScript embed footer components.
+pub fn build_footer(
+    components: &[Spanned<EmbedFooterComponent>],
+    footer_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredEmbedFooter, BuildError> {
     // Create new stored embed footer
     let mut footer = StoredEmbedFooter::new("");
 
@@ -258,45 +406,57 @@ pub fn build_footer(components: Vec<EmbedFooterComponent>) -> Result<StoredEmbed
 
     // Iterate over components
     for component in components {
-        match component {
-            EmbedFooterComponent::Text(text) => {
-                // Get footer text length
-                let text_length = text.len();
+        // Get component span
+        let span = component.span;
 
+        match &component.node {
+            EmbedFooterComponent::Text(text) => {
                 // Check if footer text has already been set
-                if text_set {
-                    // Return multiple footer text error
-                    return Err(String::from(
-                        "You can only have one footer text for an embed.",
-                    ));
-                } else if text_length > 2048 {
-                    // Return length error
-                    return Err(format!(
-                        "The length of your footer text ({}) is above the maximum of 2048 characters.",
-                        text_length
-                    ));
-                } else {
-                    // Add text to embed footer
-                    footer = footer.text(text);
-
-                    // Set text set flag
-                    text_set = true;
-                }
+                check_duplicate(
+                    text_set,
+                    "You can only have one footer text for an embed.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Add text to embed footer
+                let text = check_length(
+                    text,
+                    "footer text",
+                    options.limits.footer_text,
+                    span,
+                    options,
+                    warnings,
+                )?;
+                footer = footer.text(text);
+
+                // Set text set flag
+                text_set = true;
             }
             EmbedFooterComponent::IconUrl(icon_url) => {
                 // Check if the footer icon url is valid.
-                validation::url(&icon_url, "Embed Footer Icon URL")?;
+                validation::url(icon_url, "Embed Footer Icon URL")
+                    .map_err(|error| BuildError::new(span, error))?;
 
                 // Check if url has already been set
-                if footer.icon_url.is_some() {
-                    // Return multiple icon url error
-                    return Err(String::from(
-                        "You can only have one icon url for an embed footer.",
-                    ));
-                } else {
-                    // Set embed icon footer url
-                    footer = footer.icon_url(icon_url);
-                }
+                check_duplicate(
+                    footer.icon_url.is_some(),
+                    "You can only have one icon url for an embed footer.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the icon url looks like an image
+                check_image_extension(icon_url, "footer icon", span, warnings);
+
+                // Set embed icon footer url
+                footer = footer.icon_url(icon_url);
+            }
+            EmbedFooterComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
             }
         }
     }
@@ -304,24 +464,30 @@ pub fn build_footer(components: Vec<EmbedFooterComponent>) -> Result<StoredEmbed
     // Check if embed footer has some field set
     if footer.text.is_empty() && footer.icon_url.is_none() {
         // Return empty footer error
-        return Err(String::from("An embed cannot have an empty footer."));
+        return Err(BuildError::new(
+            footer_span,
+            String::from("An embed cannot have an empty footer."),
+        ));
     }
 
     // Return embed footer
     Ok(footer)
 }
 
-// Build a vector of stored embed field structs using a vector of vectors of VeaScript embed field components.
+// Build a vector of stored embed field structs using a slice of vectors of VeaScript embed field components.
 pub fn build_fields(
-    fields: Vec<Vec<EmbedFieldComponent>>,
-) -> Result<Vec<StoredEmbedField>, String> {
-    println!("{:?}", fields);
-
+    fields: &[Spanned<Vec<Spanned<EmbedFieldComponent>>>],
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<Vec<StoredEmbedField>, BuildError> {
     // Create built fields vector
     let mut built_fields: Vec<StoredEmbedField> = Vec::new();
 
     // Iterate over fields
     for field_components in fields {
+        // Get field span
+        let field_span = field_components.span;
+
         // Create field
         let mut field = StoredEmbedField::new();
 
@@ -331,70 +497,86 @@ pub fn build_fields(
         let mut inline_set = false;
 
         // Iterate over field components
-        for component in field_components {
-            match component {
-                EmbedFieldComponent::Name(name) => {
-                    // Get field name length
-                    let name_length = name.len();
+        for component in &field_components.node {
+            // Get component span
+            let span = component.span;
 
+            match &component.node {
+                EmbedFieldComponent::Name(name) => {
                     // Check if field name has already been set
-                    if name_set {
-                        // Return multiple field name error
-                        return Err(String::from(
-                            "You can only have one field name per embed field.",
-                        ));
-                    } else if name_length > 256 {
-                        // Return length error
-                        return Err(format!(
-                        "The length of one of your field names ({}) is above the maximum of 256 characters.",
-                        name_length
-                    ));
-                    } else {
-                        // Add name to embed field
-                        field = field.name(name);
+                    check_duplicate(
+                        name_set,
+                        "You can only have one field name per embed field.",
+                        span,
+                        options,
+                        warnings,
+                    )?;
+
+                    // Add name to embed field
+                    let name = check_length(
+                        name,
+                        "field name",
+                        options.limits.field_name,
+                        span,
+                        options,
+                        warnings,
+                    )?;
+                    field = field.name(name);
 
-                        // Set name set flag
-                        name_set = true;
-                    }
+                    // Set name set flag
+                    name_set = true;
                 }
                 EmbedFieldComponent::Value(value) => {
-                    // Get field value length
-                    let value_length = value.len();
-
                     // Check if field value has already been set
-                    if value_set {
-                        // Return multiple field value error
-                        return Err(String::from(
-                            "You can only have one field value per embed field.",
+                    check_duplicate(
+                        value_set,
+                        "You can only have one field value per embed field.",
+                        span,
+                        options,
+                        warnings,
+                    )?;
+
+                    // Add value to embed field
+                    let value = check_length(
+                        value,
+                        "field value",
+                        options.limits.field_value,
+                        span,
+                        options,
+                        warnings,
+                    )?;
+                    // Check if field value is only whitespace
+                    if !value.is_empty() && value.trim().is_empty() {
+                        warnings.push(BuildWarning::new(
+                            span,
+                            "One of your field values only contains whitespace, so it may not display.",
                         ));
-                    } else if value_length > 1024 {
-                        // Return length error
-                        return Err(format!(
-                        "The length of one of your field values ({}) is above the maximum of 1024 characters.",
-                        value_length
-                    ));
-                    } else {
-                        // Add value to embed field
-                        field = field.value(value);
-
-                        // Set value set flag
-                        value_set = true;
                     }
+
+                    field = field.value(value);
+
+                    // Set value set flag
+                    value_set = true;
                 }
                 EmbedFieldComponent::Inline(inline) => {
                     // Check if field value has already been set
-                    if inline_set {
-                        // Return multiple field value error
-                        return Err(String::from(
-                            "You can only set inline once per embed field.",
-                        ));
-                    } else {
-                        // Add inline to embed field
-                        field = field.inline(inline);
-
-                        // Set inline set flag
-                        inline_set = true;
-                    }
+                    check_duplicate(
+                        inline_set,
+                        "You can only set inline once per embed field.",
+                        span,
+                        options,
+                        warnings,
+                    )?;
+
+                    // Add inline to embed field
+                    field = field.inline(*inline);
+
+                    // Set inline set flag
+                    inline_set = true;
+                }
+                EmbedFieldComponent::Unknown(error) => {
+                    // Return unknown tag error
+                    return Err(BuildError::new(span, error));
                 }
             }
         }
@@ -402,8 +584,9 @@ pub fn build_fields(
         // Check if embed field has name and value set
         if field.name.is_empty() || field.value.is_empty() {
             // Return not set field error
-            return Err(String::from(
-                "All of your embed fields must have both a name and a value.",
+            return Err(BuildError::new(
+                field_span,
+                String::from("All of your embed fields must have both a name and a value."),
             ));
         }
 
@@ -414,3 +597,77 @@ pub fn build_fields(
     // Return embed fields
     Ok(built_fields)
 }
+
+/// Check that a component hasn't already been set, or warn that the last one is used when lenient.
+pub(super) fn check_duplicate(
+    set: bool,
+    error: &str,
+    span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<(), BuildError> {
+    if !set {
+        Ok(())
+    } else if options.lenient {
+        // Add duplicate warning
+        warnings.push(BuildWarning::new(
+            span,
+            format!("{} Only the last one was used.", error),
+        ));
+        Ok(())
+    } else {
+        // Return duplicate error
+        Err(BuildError::new(span, error))
+    }
+}
+
+/// Check that some text is within its limit, or shorten it when lenient.
+pub(super) fn check_length(
+    text: &str,
+    name: &str,
+    max: usize,
+    span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<String, BuildError> {
+    // Get text length
+    let text_length = options.limits.length(text);
+
+    // Return text if it is within the limit
+    if text_length <= max {
+        return Ok(text.to_string());
+    }
+
+    // Create length error
+    let error = format!(
+        "The length of your {} ({}) is above the maximum of {} characters.",
+        name, text_length, max
+    );
+
+    if options.lenient {
+        // Add length warning and return shortened text
+        warnings.push(BuildWarning::new(
+            span,
+            format!("{} It was shortened to fit.", error),
+        ));
+        Ok(length::truncate(text, max, options.limits.unit))
+    } else {
+        // Return length error
+        Err(BuildError::new(span, error))
+    }
+}
+
+/// Warn if an image url doesn't end with an image file extension.
+fn check_image_extension(url: &str, name: &str, span: Span, warnings: &mut Vec<BuildWarning>) {
+    if !validation::has_image_extension(url) {
+        warnings.push(BuildWarning::new(
+            span,
+            format!(
+                "Your {} url ({}) doesn't end with an image file extension ({}), so it may not display.",
+                name,
+                url,
+                validation::IMAGE_EXTENSIONS.join(", ")
+            ),
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+use crate::builders;
+use crate::enums::Expr;
+use crate::runners;
+use crate::structs::{BuildError, BuildOptions, BuildWarning, Spanned, StoredMessage};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Build a stored message struct using a slice of VeaScript expressions.
+pub fn build_message(
+    expressions: &[Spanned<Expr>],
+    rng: &mut impl Rng,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredMessage, BuildError> {
+    // Create new stored message
+    let mut message = StoredMessage::new();
+
+    // Add each expression to the message
+    for expression in expressions {
+        build_expression(expression, &mut message, rng, options, warnings)?;
+    }
+
+    // Return message
+    Ok(message)
+}
+
+/// Add the result of a VeaScript expression to a stored message.
+pub fn build_expression(
+    expression: &Spanned<Expr>,
+    message: &mut StoredMessage,
+    rng: &mut impl Rng,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<(), BuildError> {
+    match &expression.node {
+        Expr::Math(math) => {
+            message.push_content(runners::eval(&math.node).to_string());
+        }
+        Expr::Random(choices) => {
+            if let Some(choice) = choices.choose(rng) {
+                message.push_content(choice);
+            }
+        }
+        Expr::Text(text) => {
+            message.push_content(text);
+        }
+        Expr::Embed(components) => {
+            let embed = builders::embed(components, expression.span, rng, options, warnings)?;
+            message.push_embed(embed);
+        }
+        Expr::Attachment(components) => {
+            let attachment = builders::attachment(components, expression.span, options, warnings)?;
+            message.push_attachment(attachment);
+        }
+        Expr::ActionRow(components) => {
+            let row = builders::action_row(components, expression.span, options, warnings)?;
+            message.push_action_row(row);
+        }
+        Expr::Poll(components) => {
+            // Check if the message already has a poll
+            if message.poll.is_some() {
+                return Err(BuildError::new(
+                    expression.span,
+                    "A message can only have one poll.",
+                ));
+            }
+
+            let poll = builders::poll(components, expression.span, rng, options, warnings)?;
+            message.poll = Some(poll);
+        }
+        Expr::Settings(components) => {
+            // Check if the message already has settings
+            if message.settings.is_some() {
+                return Err(BuildError::new(
+                    expression.span,
+                    "A message can only have one #settings block.",
+                ));
+            }
+
+            message.settings = Some(builders::settings(components, options, warnings)?);
+        }
+        Expr::AllowedMentions(components) => {
+            // Check if the message already has allowed mentions
+            if message.allowed_mentions.is_some() {
+                return Err(BuildError::new(
+                    expression.span,
+                    "A message can only have one #allowed_mentions block.",
+                ));
+            }
+
+            message.allowed_mentions =
+                Some(builders::allowed_mentions(components, options, warnings)?);
+        }
+        Expr::Webhook(components) => {
+            // Check if the message already has a webhook identity
+            if message.webhook.is_some() {
+                return Err(BuildError::new(
+                    expression.span,
+                    "A message can only have one #webhook block.",
+                ));
+            }
+
+            let webhook = builders::webhook(components, expression.span, options, warnings)?;
+            message.webhook = Some(webhook);
+        }
+        Expr::Handler(..) => {
+            return Err(BuildError::new(
+                expression.span,
+                "A handler block can only be placed at the top level of a script.",
+            ));
+        }
+        Expr::Message(_) => {
+            return Err(BuildError::new(
+                expression.span,
+                "A message block can't be placed inside another message.",
+            ));
+        }
+    }
+
+    // Return success
+    Ok(())
+}
@@ -1,5 +1,17 @@
 // Import parser modules
+mod attachment;
+mod component;
 mod embed;
+mod message;
+mod poll;
+mod settings;
+mod webhook;
 
 // Re-export parsers
+pub use attachment::build_attachment as attachment;
+pub use component::build_action_row as action_row;
 pub use embed::build_embed as embed;
+pub use message::{build_expression as expression, build_message as message};
+pub use poll::build_poll as poll;
+pub use settings::{build_allowed_mentions as allowed_mentions, build_settings as settings};
+pub use webhook::build_webhook as webhook;
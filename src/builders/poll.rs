@@ -0,0 +1,201 @@
+use crate::builders::component::check_emoji;
+use crate::builders::embed::{check_duplicate, check_length};
+use crate::enums::{PollAnswerComponent, PollComponent};
+use crate::structs::{
+    BuildError, BuildOptions, BuildWarning, Span, Spanned, StoredPoll, StoredPollAnswer,
+};
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Build a stored poll struct using a slice of VeaScript poll components.
+pub fn build_poll(
+    components: &[Spanned<PollComponent>],
+    poll_span: Span,
+    rng: &mut impl Rng,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredPoll, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create poll parts
+    let mut poll = StoredPoll::new("");
+    let mut question: Option<String> = None;
+    let mut duration: Option<u32> = None;
+    let mut shuffle = false;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            PollComponent::Question(text) => {
+                // Check if question has already been set
+                check_duplicate(
+                    question.is_some(),
+                    "You can only have one question for a poll.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set question
+                question = Some(check_length(
+                    text,
+                    "poll question",
+                    limits.poll_question,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            PollComponent::Answer(components) => {
+                // Check if answer limit has been exceeded
+                if poll.answers.len() >= limits.poll_answers {
+                    return Err(BuildError::new(
+                        span,
+                        format!(
+                            "A poll can only have a maximum of {} answers.",
+                            limits.poll_answers
+                        ),
+                    ));
+                }
+
+                // Add answer to poll
+                let answer = build_poll_answer(components, span, options, warnings)?;
+                poll.answers.push(answer);
+            }
+            PollComponent::Duration(hours) => {
+                // Check if duration has already been set
+                check_duplicate(
+                    duration.is_some(),
+                    "You can only have one duration for a poll.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check that the duration is in range
+                let hours = u32::try_from(*hours)
+                    .ok()
+                    .filter(|hours| (1..=limits.poll_duration).contains(hours))
+                    .ok_or_else(|| {
+                        BuildError::new(
+                            span,
+                            format!(
+                                "A poll's duration must be between 1 and {} hours.",
+                                limits.poll_duration
+                            ),
+                        )
+                    })?;
+
+                // Set duration
+                duration = Some(hours);
+            }
+            PollComponent::MultiSelect(multi_select) => {
+                // Set whether more than one answer can be chosen
+                poll.multi_select = *multi_select;
+            }
+            PollComponent::Shuffle(value) => {
+                // Set whether answers are shuffled
+                shuffle = *value;
+            }
+            PollComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Set question and duration
+    poll.question =
+        question.ok_or_else(|| BuildError::new(poll_span, "A poll needs a #question."))?;
+
+    if let Some(duration) = duration {
+        poll.duration = duration;
+    }
+
+    // Shuffle answers
+    if shuffle {
+        poll.answers.shuffle(rng);
+    }
+
+    // Check poll
+    poll.validate(limits)
+        .map_err(|error| BuildError::new(poll_span, error.message))?;
+
+    // Return poll
+    Ok(poll)
+}
+
+/// Build a stored poll answer struct using a slice of VeaScript poll answer components.
+pub fn build_poll_answer(
+    components: &[Spanned<PollAnswerComponent>],
+    answer_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredPollAnswer, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create answer parts
+    let mut label: Option<String> = None;
+    let mut emoji: Option<String> = None;
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            PollAnswerComponent::Label(text) => {
+                // Check if label has already been set
+                check_duplicate(
+                    label.is_some(),
+                    "You can only have one label for a poll answer.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set label
+                label = Some(check_length(
+                    text,
+                    "poll answer",
+                    limits.poll_answer_text,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            PollAnswerComponent::Emoji(text) => {
+                // Check if emoji has already been set
+                check_duplicate(
+                    emoji.is_some(),
+                    "You can only have one emoji for a poll answer.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check and set emoji
+                check_emoji(text, span)?;
+                emoji = Some(text.clone());
+            }
+            PollAnswerComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Create answer
+    let label =
+        label.ok_or_else(|| BuildError::new(answer_span, "A poll answer needs a #label."))?;
+    let mut answer = StoredPollAnswer::new(label);
+    answer.emoji = emoji;
+
+    // Return answer
+    Ok(answer)
+}
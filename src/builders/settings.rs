@@ -0,0 +1,175 @@
+use crate::builders::embed::check_duplicate;
+use crate::enums::{AllowedMentionsComponent, SettingsComponent};
+use crate::structs::{
+    BuildError, BuildOptions, BuildWarning, MessageSettings, Spanned, StoredAllowedMentions,
+};
+
+/// Build message settings using a slice of VeaScript settings components.
+pub fn build_settings(
+    components: &[Spanned<SettingsComponent>],
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<MessageSettings, BuildError> {
+    // Create settings
+    let mut settings = MessageSettings::new();
+
+    // Track which settings have been set
+    let (mut suppress_embeds, mut silent, mut ephemeral, mut tts) = (false, false, false, false);
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            SettingsComponent::SuppressEmbeds(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    suppress_embeds,
+                    "You can only have one setting for suppressing embeds.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                suppress_embeds = true;
+
+                // Set whether link embeds are hidden
+                settings.suppress_embeds = *value;
+            }
+            SettingsComponent::Silent(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    silent,
+                    "You can only have one setting for silent messages.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                silent = true;
+
+                // Set whether notifications are sent
+                settings.silent = *value;
+            }
+            SettingsComponent::Ephemeral(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    ephemeral,
+                    "You can only have one setting for ephemeral messages.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                ephemeral = true;
+
+                // Set whether the message is only visible to the user who used an interaction
+                settings.ephemeral = *value;
+            }
+            SettingsComponent::Tts(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    tts,
+                    "You can only have one setting for text-to-speech.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                tts = true;
+
+                // Set whether the message is read aloud
+                settings.tts = *value;
+            }
+            SettingsComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error.clone()));
+            }
+        }
+    }
+
+    // Return settings
+    Ok(settings)
+}
+
+/// Build allowed mentions using a slice of VeaScript allowed mentions components.
+pub fn build_allowed_mentions(
+    components: &[Spanned<AllowedMentionsComponent>],
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredAllowedMentions, BuildError> {
+    // Create allowed mentions, starting from the default
+    let mut allowed_mentions = StoredAllowedMentions::new();
+
+    // Track which mentions have been set
+    let (mut users, mut roles, mut everyone, mut replied_user) = (false, false, false, false);
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            AllowedMentionsComponent::Users(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    users,
+                    "You can only have one setting for user mentions.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                users = true;
+
+                // Set whether user mentions notify
+                allowed_mentions.users = *value;
+            }
+            AllowedMentionsComponent::Roles(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    roles,
+                    "You can only have one setting for role mentions.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                roles = true;
+
+                // Set whether role mentions notify
+                allowed_mentions.roles = *value;
+            }
+            AllowedMentionsComponent::Everyone(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    everyone,
+                    "You can only have one setting for @everyone mentions.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                everyone = true;
+
+                // Set whether @everyone and @here notify
+                allowed_mentions.everyone = *value;
+            }
+            AllowedMentionsComponent::RepliedUser(value) => {
+                // Check if this has already been set
+                check_duplicate(
+                    replied_user,
+                    "You can only have one setting for reply mentions.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+                replied_user = true;
+
+                // Set whether replies notify
+                allowed_mentions.replied_user = *value;
+            }
+            AllowedMentionsComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error.clone()));
+            }
+        }
+    }
+
+    // Return allowed mentions
+    Ok(allowed_mentions)
+}
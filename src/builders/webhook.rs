@@ -0,0 +1,96 @@
+use crate::builders::embed::{check_duplicate, check_length};
+use crate::enums::WebhookComponent;
+use crate::helpers::validation;
+use crate::structs::{BuildError, BuildOptions, BuildWarning, Span, Spanned, StoredWebhook};
+
+/// Build a stored webhook identity using a slice of VeaScript webhook components.
+pub fn build_webhook(
+    components: &[Spanned<WebhookComponent>],
+    webhook_span: Span,
+    options: &BuildOptions,
+    warnings: &mut Vec<BuildWarning>,
+) -> Result<StoredWebhook, BuildError> {
+    // Get limits
+    let limits = &options.limits;
+
+    // Create webhook identity
+    let mut webhook = StoredWebhook::new();
+
+    // Iterate over components
+    for component in components {
+        // Get component span
+        let span = component.span;
+
+        match &component.node {
+            WebhookComponent::Username(username) => {
+                // Check if username has already been set
+                check_duplicate(
+                    webhook.username.is_some(),
+                    "You can only have one username for a webhook.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Check if the username is allowed
+                validation::webhook_username(username)
+                    .map_err(|error| BuildError::new(span, error))?;
+
+                // Set username
+                webhook.username = Some(check_length(
+                    username,
+                    "webhook username",
+                    limits.webhook_username,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            WebhookComponent::AvatarUrl(avatar_url) => {
+                // Check if avatar URL has already been set
+                check_duplicate(
+                    webhook.avatar_url.is_some(),
+                    "You can only have one avatar URL for a webhook.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set avatar URL
+                webhook.avatar_url = Some(avatar_url.clone());
+            }
+            WebhookComponent::ThreadName(thread_name) => {
+                // Check if thread name has already been set
+                check_duplicate(
+                    webhook.thread_name.is_some(),
+                    "You can only have one thread name for a webhook.",
+                    span,
+                    options,
+                    warnings,
+                )?;
+
+                // Set thread name
+                webhook.thread_name = Some(check_length(
+                    thread_name,
+                    "thread name",
+                    limits.thread_name,
+                    span,
+                    options,
+                    warnings,
+                )?);
+            }
+            WebhookComponent::Unknown(error) => {
+                // Return unknown tag error
+                return Err(BuildError::new(span, error));
+            }
+        }
+    }
+
+    // Check webhook identity
+    webhook
+        .validate(limits)
+        .map_err(|error| BuildError::new(webhook_span, error.message))?;
+
+    // Return webhook identity
+    Ok(webhook)
+}
@@ -0,0 +1,58 @@
+use crate::decompilers::embed::{push_block_end, push_block_start, push_string, push_value, quote};
+use crate::enums::Keyword;
+use crate::structs::StoredAttachment;
+
+/// Decompile a stored attachment into canonical VeaScript source.
+///
+/// Text attachments are written inline with `#content`. Other files are written with `#file`,
+/// which expects the file to be in the asset directory under the same name.
+pub fn decompile_attachment(attachment: &StoredAttachment) -> String {
+    decompile_attachment_at(attachment, 0)
+}
+
+/// Decompile a stored attachment into canonical VeaScript source, nested at the given depth.
+pub(super) fn decompile_attachment_at(attachment: &StoredAttachment, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Open attachment block
+    push_block_start(&mut source, depth, Keyword::Attachment);
+
+    // Add file name
+    push_value(
+        &mut source,
+        depth + 1,
+        Keyword::Filename,
+        quote(&attachment.filename),
+    );
+
+    // Add contents, inline if they are text
+    match std::str::from_utf8(&attachment.data) {
+        Ok(content) => push_value(&mut source, depth + 1, Keyword::Content, quote(content)),
+        Err(_) => push_value(
+            &mut source,
+            depth + 1,
+            Keyword::File,
+            quote(&attachment.filename),
+        ),
+    }
+
+    // Add description
+    push_string(
+        &mut source,
+        depth + 1,
+        Keyword::Description,
+        &attachment.description,
+    );
+
+    // Add spoiler, which is left out when it is the default
+    if attachment.spoiler {
+        push_value(&mut source, depth + 1, Keyword::Spoiler, "true".to_string());
+    }
+
+    // Close attachment block
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
@@ -0,0 +1,154 @@
+use crate::decompilers::embed::{push_block_end, push_block_start, push_string, push_value, quote};
+use crate::enums::{ButtonStyle, Keyword, SelectKind};
+use crate::structs::{
+    ComponentRoute, StoredActionRow, StoredButton, StoredSelect, StoredSelectOption,
+};
+
+/// Decompile a stored action row into canonical VeaScript source.
+pub fn decompile_action_row(row: &StoredActionRow) -> String {
+    decompile_action_row_at(row, 0)
+}
+
+/// Decompile a stored action row into canonical VeaScript source, nested at the given depth.
+pub(super) fn decompile_action_row_at(row: &StoredActionRow, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Open action row block
+    push_block_start(&mut source, depth, Keyword::ActionRow);
+
+    // Add buttons or select menu
+    match row {
+        StoredActionRow::Buttons(buttons) => {
+            for button in buttons {
+                decompile_button(&mut source, depth + 1, button);
+            }
+        }
+        StoredActionRow::Select(select) => decompile_select(&mut source, depth + 1, select),
+    }
+
+    // Close action row block
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
+
+/// Decompile a button into a button block.
+fn decompile_button(source: &mut String, depth: usize, button: &StoredButton) {
+    push_block_start(source, depth, Keyword::Button);
+
+    // Add style, which is left out when it would be inferred
+    let inferred = if button.url.is_some() {
+        ButtonStyle::Link
+    } else {
+        ButtonStyle::Primary
+    };
+
+    if button.style != inferred {
+        push_value(
+            source,
+            depth + 1,
+            Keyword::Style,
+            button.style.name().to_string(),
+        );
+    }
+
+    // Add text components
+    push_string(source, depth + 1, Keyword::Label, &button.label);
+    push_string(source, depth + 1, Keyword::Emoji, &button.emoji);
+
+    if let Some(custom_id) = &button.custom_id {
+        push_custom_id(source, depth + 1, custom_id);
+    }
+
+    push_string(source, depth + 1, Keyword::Url, &button.url);
+
+    // Add disabled state, which is left out when it is the default
+    if button.disabled {
+        push_value(source, depth + 1, Keyword::Disabled, "true".to_string());
+    }
+
+    push_block_end(source, depth);
+}
+
+/// Decompile a select menu into a select block.
+fn decompile_select(source: &mut String, depth: usize, select: &StoredSelect) {
+    push_block_start(source, depth, Keyword::Select);
+
+    // Add type, which is left out when it is the default
+    if select.kind != SelectKind::String {
+        push_value(
+            source,
+            depth + 1,
+            Keyword::Type,
+            select.kind.name().to_string(),
+        );
+    }
+
+    // Add text components
+    push_custom_id(source, depth + 1, &select.custom_id);
+    push_string(source, depth + 1, Keyword::Placeholder, &select.placeholder);
+
+    // Add value counts
+    if let Some(min_values) = select.min_values {
+        push_value(
+            source,
+            depth + 1,
+            Keyword::MinValues,
+            min_values.to_string(),
+        );
+    }
+
+    if let Some(max_values) = select.max_values {
+        push_value(
+            source,
+            depth + 1,
+            Keyword::MaxValues,
+            max_values.to_string(),
+        );
+    }
+
+    // Add disabled state, which is left out when it is the default
+    if select.disabled {
+        push_value(source, depth + 1, Keyword::Disabled, "true".to_string());
+    }
+
+    // Add options
+    for option in &select.options {
+        decompile_select_option(source, depth + 1, option);
+    }
+
+    push_block_end(source, depth);
+}
+
+/// Decompile a select menu option into an option block.
+fn decompile_select_option(source: &mut String, depth: usize, option: &StoredSelectOption) {
+    push_block_start(source, depth, Keyword::Option);
+    push_value(source, depth + 1, Keyword::Label, quote(&option.label));
+    push_value(source, depth + 1, Keyword::Value, quote(&option.value));
+    push_string(source, depth + 1, Keyword::Description, &option.description);
+    push_string(source, depth + 1, Keyword::Emoji, &option.emoji);
+
+    // Add default state, which is left out when it is the default
+    if option.default {
+        push_value(source, depth + 1, Keyword::Default, "true".to_string());
+    }
+
+    push_block_end(source, depth);
+}
+
+/// Add a component's custom id, written as its handler and state if it is routed to a handler.
+fn push_custom_id(source: &mut String, depth: usize, custom_id: &str) {
+    match ComponentRoute::decode(custom_id) {
+        Some(route) => {
+            // Add handler, with the state left out when it is empty
+            push_value(source, depth, Keyword::Handler, quote(&route.handler));
+
+            if !route.state.is_empty() {
+                push_value(source, depth, Keyword::State, quote(&route.state));
+            }
+        }
+        None => push_value(source, depth, Keyword::CustomId, quote(custom_id)),
+    }
+}
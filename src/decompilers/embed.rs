@@ -0,0 +1,166 @@
+use crate::enums::Keyword;
+use crate::helpers::{escape, hexadecimal};
+use crate::structs::{StoredEmbed, StoredEmbedAuthor, StoredEmbedField, StoredEmbedFooter};
+
+/// The indentation used for each level of nesting.
+pub(super) const INDENT: &str = "    ";
+
+/// Decompile a stored embed into canonical VeaScript source.
+pub fn decompile_embed(embed: &StoredEmbed) -> String {
+    decompile_embed_at(embed, 0)
+}
+
+/// Decompile a stored embed into canonical VeaScript source, nested at the given depth.
+pub(super) fn decompile_embed_at(embed: &StoredEmbed, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Open embed block
+    push_block_start(&mut source, depth, Keyword::Embed);
+
+    // Add text components
+    push_string(&mut source, depth + 1, Keyword::Title, &embed.title);
+    push_string(
+        &mut source,
+        depth + 1,
+        Keyword::Description,
+        &embed.description,
+    );
+    push_string(&mut source, depth + 1, Keyword::Url, &embed.url);
+
+    // Add colour, which is left out when it is the default
+    if embed.colour != 0 {
+        push_value(
+            &mut source,
+            depth + 1,
+            Keyword::Colour,
+            format!("#{}", hexadecimal::hex_to_str(embed.colour as i64)),
+        );
+    }
+
+    // Add author and footer
+    if let Some(author) = &embed.author {
+        decompile_author(&mut source, depth, author);
+    }
+
+    if let Some(footer) = &embed.footer {
+        decompile_footer(&mut source, depth, footer);
+    }
+
+    // Add images
+    push_string(&mut source, depth + 1, Keyword::Image, &embed.image_url);
+    push_string(
+        &mut source,
+        depth + 1,
+        Keyword::Thumbnail,
+        &embed.thumbnail_url,
+    );
+
+    // Add timestamp
+    if let Some(timestamp) = embed.timestamp {
+        push_value(
+            &mut source,
+            depth + 1,
+            Keyword::Timestamp,
+            timestamp.to_string(),
+        );
+    }
+
+    // Add fields
+    if !embed.fields.is_empty() {
+        push_block_start(&mut source, depth + 1, Keyword::Fields);
+
+        for field in &embed.fields {
+            decompile_field(&mut source, depth, field);
+        }
+
+        push_block_end(&mut source, depth + 1);
+    }
+
+    // Close embed block
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
+
+/// Decompile an embed author into an author block.
+fn decompile_author(source: &mut String, depth: usize, author: &StoredEmbedAuthor) {
+    push_block_start(source, depth + 1, Keyword::Author);
+
+    // Add name, which is left out when it is empty
+    if !author.name.is_empty() {
+        push_value(source, depth + 2, Keyword::Name, quote(&author.name));
+    }
+
+    // Add urls
+    push_string(source, depth + 2, Keyword::Url, &author.url);
+    push_string(source, depth + 2, Keyword::IconUrl, &author.icon_url);
+
+    push_block_end(source, depth + 1);
+}
+
+/// Decompile an embed footer into a footer block.
+fn decompile_footer(source: &mut String, depth: usize, footer: &StoredEmbedFooter) {
+    push_block_start(source, depth + 1, Keyword::Footer);
+
+    // Add text, which is left out when it is empty
+    if !footer.text.is_empty() {
+        push_value(source, depth + 2, Keyword::Text, quote(&footer.text));
+    }
+
+    // Add icon url
+    push_string(source, depth + 2, Keyword::IconUrl, &footer.icon_url);
+
+    push_block_end(source, depth + 1);
+}
+
+/// Decompile an embed field into a field block.
+fn decompile_field(source: &mut String, depth: usize, field: &StoredEmbedField) {
+    push_block_start(source, depth + 2, Keyword::Field);
+    push_value(source, depth + 3, Keyword::Name, quote(&field.name));
+    push_value(source, depth + 3, Keyword::Value, quote(&field.value));
+    push_value(source, depth + 3, Keyword::Inline, field.inline.to_string());
+    push_block_end(source, depth + 2);
+}
+
+/// Quote and escape a string.
+pub(super) fn quote(text: &str) -> String {
+    format!("\"{}\"", escape::string(text))
+}
+
+/// Add a `#tag: value,` line.
+pub(super) fn push_value(source: &mut String, depth: usize, keyword: Keyword, value: String) {
+    source.push_str(&format!(
+        "{}#{}: {},\n",
+        INDENT.repeat(depth),
+        keyword.canonical(),
+        value
+    ));
+}
+
+/// Add a `#tag: "value",` line if the value is set.
+pub(super) fn push_string(
+    source: &mut String,
+    depth: usize,
+    keyword: Keyword,
+    value: &Option<String>,
+) {
+    if let Some(value) = value {
+        push_value(source, depth, keyword, quote(value));
+    }
+}
+
+/// Add the opening line of a `#tag { ... }` block.
+pub(super) fn push_block_start(source: &mut String, depth: usize, keyword: Keyword) {
+    source.push_str(&format!(
+        "{}#{} {{\n",
+        INDENT.repeat(depth),
+        keyword.canonical()
+    ));
+}
+
+/// Add the closing line of a block.
+pub(super) fn push_block_end(source: &mut String, depth: usize) {
+    source.push_str(&format!("{}}}\n", INDENT.repeat(depth)));
+}
@@ -0,0 +1,102 @@
+use crate::decompilers::attachment::decompile_attachment_at;
+use crate::decompilers::component::decompile_action_row_at;
+use crate::decompilers::embed::{decompile_embed_at, push_block_end, push_block_start, INDENT};
+use crate::decompilers::poll::decompile_poll_at;
+use crate::decompilers::settings::{decompile_allowed_mentions_at, decompile_settings_at};
+use crate::decompilers::webhook::decompile_webhook_at;
+use crate::enums::Keyword;
+use crate::helpers::escape;
+use crate::structs::{MessageSettings, StoredAllowedMentions, StoredMessage};
+
+/// Decompile a stored message into canonical VeaScript source.
+pub fn decompile_message(message: &StoredMessage) -> String {
+    decompile_blocks(message, 0).join("\n")
+}
+
+/// Decompile a stored message into a `#message { ... }` block.
+pub(super) fn decompile_message_block(message: &StoredMessage) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Add message blocks inside a message block
+    push_block_start(&mut source, 0, Keyword::Message);
+    source.push_str(&decompile_blocks(message, 1).join("\n"));
+    push_block_end(&mut source, 0);
+
+    // Return source
+    source
+}
+
+/// Decompile the parts of a stored message into source blocks.
+fn decompile_blocks(message: &StoredMessage, depth: usize) -> Vec<String> {
+    // Create source blocks
+    let mut blocks = Vec::new();
+
+    // Add content as a text block, without indenting the text itself
+    if !message.content.is_empty() {
+        blocks.push(format!(
+            "{}#{} {{{}}}\n",
+            INDENT.repeat(depth),
+            Keyword::Text.canonical(),
+            escape::text(&message.content)
+        ));
+    }
+
+    // Add embeds
+    blocks.extend(
+        message
+            .embeds
+            .iter()
+            .map(|embed| decompile_embed_at(embed, depth)),
+    );
+
+    // Add attachments
+    blocks.extend(
+        message
+            .attachments
+            .iter()
+            .map(|attachment| decompile_attachment_at(attachment, depth)),
+    );
+
+    // Add action rows
+    blocks.extend(
+        message
+            .components
+            .iter()
+            .map(|row| decompile_action_row_at(row, depth)),
+    );
+
+    // Add poll
+    blocks.extend(
+        message
+            .poll
+            .iter()
+            .map(|poll| decompile_poll_at(poll, depth)),
+    );
+
+    // Add settings and allowed mentions, unless they are the default
+    if let Some(settings) = message
+        .settings
+        .filter(|settings| *settings != MessageSettings::default())
+    {
+        blocks.push(decompile_settings_at(&settings, depth));
+    }
+
+    if let Some(allowed_mentions) = message
+        .allowed_mentions
+        .filter(|allowed_mentions| *allowed_mentions != StoredAllowedMentions::default())
+    {
+        blocks.push(decompile_allowed_mentions_at(&allowed_mentions, depth));
+    }
+
+    // Add webhook identity
+    blocks.extend(
+        message
+            .webhook
+            .iter()
+            .map(|webhook| decompile_webhook_at(webhook, depth)),
+    );
+
+    // Return blocks
+    blocks
+}
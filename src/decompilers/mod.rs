@@ -0,0 +1,21 @@
+// Import decompiler modules
+mod attachment;
+mod component;
+mod embed;
+mod message;
+mod output;
+mod poll;
+mod settings;
+mod webhook;
+
+// Re-export decompilers
+pub use attachment::decompile_attachment as attachment;
+pub use component::decompile_action_row as action_row;
+pub use embed::decompile_embed as embed;
+pub use message::decompile_message as message;
+pub use output::decompile_output as output;
+pub use poll::decompile_poll as poll;
+pub use settings::{
+    decompile_allowed_mentions as allowed_mentions, decompile_settings as settings,
+};
+pub use webhook::decompile_webhook as webhook;
@@ -0,0 +1,378 @@
+use crate::decompilers;
+use crate::decompilers::embed::quote;
+use crate::decompilers::message::decompile_message_block;
+use crate::enums::Keyword;
+use crate::structs::{ComponentRoute, VeaScriptOutput};
+
+/// Decompile a VeaScript output into canonical VeaScript source.
+pub fn decompile_output(output: &VeaScriptOutput) -> String {
+    let mut blocks = match output.messages.as_slice() {
+        // Write a single message without a message block
+        [message] => vec![decompilers::message(message)],
+
+        // Wrap each message in a message block, with blocks separated by blank lines
+        messages => messages.iter().map(decompile_message_block).collect(),
+    };
+
+    // Add an empty handler block for each handler that components are routed to, since the
+    // handlers themselves aren't part of the output
+    for handler in handler_names(output) {
+        blocks.push(format!(
+            "#{} {} {{}}\n",
+            Keyword::OnClick.canonical(),
+            quote(&handler)
+        ));
+    }
+
+    // Return source
+    blocks.join("\n")
+}
+
+/// Get the names of the handlers that an output's components are routed to, in order.
+fn handler_names(output: &VeaScriptOutput) -> Vec<String> {
+    let mut names = Vec::new();
+
+    for row in output
+        .messages
+        .iter()
+        .flat_map(|message| &message.components)
+    {
+        for route in row
+            .custom_ids()
+            .into_iter()
+            .filter_map(ComponentRoute::decode)
+        {
+            if !names.contains(&route.handler) {
+                names.push(route.handler);
+            }
+        }
+    }
+
+    // Return names
+    names
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::build_veascript;
+    use crate::enums::{ButtonStyle, SelectKind};
+    use crate::helpers::validation::MAX_TIMESTAMP;
+    use crate::structs::{
+        BuildOptions, ComponentRoute, StoredActionRow, StoredButton, StoredEmbed,
+        StoredEmbedAuthor, StoredEmbedFooter, StoredMessage, StoredSelect, StoredSelectOption,
+        VeaScriptOutput, VeaScriptParser,
+    };
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    /// Characters that generated text is made of, including every character that is escaped.
+    const CHARACTERS: &[char] = &[
+        'a', 'Z', '7', ' ', '"', '\\', '{', '}', '#', ',', ':', '$', '\n', '\r', '\t', 'é', '日',
+        '😀',
+    ];
+
+    /// Handler names that generated components are routed to.
+    const HANDLERS: &[&str] = &["next", "back", "pick_1", "a-b"];
+
+    /// The script id that generated components are routed with.
+    const SCRIPT_ID: &str = "s1";
+
+    /// Build some VeaScript source, panicking if it doesn't parse or build.
+    fn build(src: &str) -> VeaScriptOutput {
+        let script = VeaScriptParser::shared()
+            .parse(0, src)
+            .unwrap_or_else(|errors| panic!("{:?}\n{}", errors, src));
+        build_veascript(&script, &BuildOptions::new().seed(0).script_id(SCRIPT_ID))
+            .unwrap_or_else(|error| panic!("{:?}\n{}", error, src))
+    }
+
+    /// Check that decompiling a script and building it again gives the same messages.
+    fn assert_round_trip(src: &str) -> String {
+        let output = build(src);
+        let decompiled = output.to_veascript();
+        let rebuilt = build(&decompiled);
+        assert_eq!(
+            rebuilt.to_discord_json().unwrap(),
+            output.to_discord_json().unwrap(),
+            "\n{}",
+            decompiled
+        );
+        output.to_discord_json().unwrap().join("\n")
+    }
+
+    #[test]
+    fn round_trips_text_escapes() {
+        assert_round_trip(r#"#text {Quotes " and slashes \\ and braces {\} and " \"}"#);
+        assert_round_trip("#text {First line\nSecond line\n\n\\}}");
+    }
+
+    #[test]
+    fn round_trips_string_escapes() {
+        assert_round_trip(
+            r#"#embed {
+                #title: "Quotes \" and slashes \\ and braces } {",
+                #description: "First line\nSecond line\r\n\tIndented \\n",
+            }"#,
+        );
+    }
+
+    #[test]
+    fn round_trips_author_and_footer_with_only_urls() {
+        let json = assert_round_trip(
+            r#"#embed {
+                #title: "Title",
+                #author {
+                    #url: "https://example.com",
+                    #icon_url: "https://example.com/icon.png",
+                }
+                #footer {
+                    #icon_url: "https://example.com/footer.png",
+                }
+            }"#,
+        );
+        assert!(json.contains("https://example.com/icon.png"));
+        assert!(json.contains("https://example.com/footer.png"));
+    }
+
+    #[test]
+    fn round_trips_colour_zero() {
+        let json = assert_round_trip(
+            r#"#embed {
+                #title: "Black",
+                #colour: #000000,
+            }"#,
+        );
+        // Colour 0 is stored as no colour, so it stays out of the payload both times
+        assert!(!json.contains("color"), "{}", json);
+    }
+
+    #[test]
+    fn round_trips_multiple_messages() {
+        assert_round_trip(
+            r#"#message {
+                #text {First \} message}
+            }
+            #message {
+                #embed {
+                    #title: "Second \"message\"",
+                    #colour: #000000,
+                }
+            }
+            #message {
+                #text {Third
+message}
+            }"#,
+        );
+    }
+
+    #[test]
+    fn round_trips_handlers() {
+        let json = assert_round_trip(
+            r#"#text {Pick one}
+            #actionrow {
+                #button {
+                    #label: "Next",
+                    #handler: "next",
+                }
+                #button {
+                    #label: "Back",
+                    #style: secondary,
+                    #handler: "back",
+                    #state: "page:2 \"quoted\"",
+                }
+            }
+            #actionrow {
+                #select {
+                    #handler: "pick",
+                    #state: "menu",
+                    #option {
+                        #label: "One",
+                        #value: "1",
+                    }
+                }
+            }
+            #on_click "next" {
+                #text {Next page}
+            }
+            #on_click "back" {
+                #text {Previous page}
+            }
+            #on_click "pick" {
+                #text {You picked $values}
+            }"#,
+        );
+        assert!(json.contains("vs:s1:next:"), "{}", json);
+        assert!(json.contains("vs:s1:pick:menu"), "{}", json);
+    }
+
+    /// Generate text of up to a given length out of characters that need escaping.
+    fn text(rng: &mut StdRng, max: usize) -> String {
+        (0..rng.gen_range(1..=max))
+            .map(|_| *CHARACTERS.choose(rng).unwrap())
+            .collect()
+    }
+
+    /// Generate some text half of the time.
+    fn maybe_text(rng: &mut StdRng, max: usize) -> Option<String> {
+        rng.gen_bool(0.5).then(|| text(rng, max))
+    }
+
+    /// Generate a URL half of the time.
+    fn maybe_url(rng: &mut StdRng) -> Option<String> {
+        rng.gen_bool(0.5)
+            .then(|| format!("https://example.com/{}.png", rng.gen_range(0..1000)))
+    }
+
+    /// Generate the custom id of a component routed to a handler, with some state. The state
+    /// ends with the component's index in its message, so that custom ids are unique.
+    fn route(rng: &mut StdRng, index: &mut usize) -> String {
+        let handler = HANDLERS.choose(rng).unwrap();
+        let mut state = if rng.gen_bool(0.5) {
+            text(rng, 20)
+        } else {
+            String::new()
+        };
+
+        // Leave the first state empty, since an empty state is written differently
+        if *index > 0 {
+            state.push_str(&index.to_string());
+        }
+
+        *index += 1;
+        ComponentRoute::new(SCRIPT_ID, *handler, state).encode()
+    }
+
+    /// Generate an embed with any of its parts.
+    fn embed(rng: &mut StdRng) -> StoredEmbed {
+        let mut embed = StoredEmbed::new();
+        embed.title = maybe_text(rng, 50);
+        embed.description = maybe_text(rng, 200);
+        embed.url = maybe_url(rng);
+        embed.image_url = maybe_url(rng);
+        embed.thumbnail_url = maybe_url(rng);
+
+        // Use colour 0 (no colour) often, since it is written differently
+        embed.colour = if rng.gen_bool(0.3) {
+            0
+        } else {
+            rng.gen_range(0..=0xFFFFFF)
+        };
+
+        // Use timestamps from the whole range that can be written as a date
+        embed.timestamp = rng.gen_bool(0.5).then(|| rng.gen_range(0..=MAX_TIMESTAMP));
+
+        // Add an author and footer, which may have only URLs
+        if rng.gen_bool(0.5) {
+            embed.author = Some(StoredEmbedAuthor {
+                name: maybe_text(rng, 50).unwrap_or_default(),
+                url: maybe_url(rng),
+                icon_url: Some(format!(
+                    "https://example.com/{}.png",
+                    rng.gen_range(0..1000)
+                )),
+            });
+        }
+
+        if rng.gen_bool(0.5) {
+            embed.footer = Some(StoredEmbedFooter {
+                text: maybe_text(rng, 50).unwrap_or_default(),
+                icon_url: Some(format!(
+                    "https://example.com/{}.png",
+                    rng.gen_range(0..1000)
+                )),
+            });
+        }
+
+        // Add fields
+        for _ in 0..rng.gen_range(0..3) {
+            embed = embed.field(text(rng, 50), text(rng, 50), rng.gen_bool(0.5));
+        }
+
+        // Make sure the embed isn't empty
+        if embed.title.is_none() && embed.description.is_none() {
+            embed.title = Some(text(rng, 50));
+        }
+
+        embed
+    }
+
+    /// Generate an action row of routed buttons or a routed select menu.
+    fn action_row(rng: &mut StdRng, index: &mut usize) -> StoredActionRow {
+        if rng.gen_bool(0.5) {
+            let styles = [
+                ButtonStyle::Primary,
+                ButtonStyle::Secondary,
+                ButtonStyle::Success,
+                ButtonStyle::Danger,
+            ];
+            let buttons = (0..rng.gen_range(1..=3))
+                .map(|_| {
+                    let mut button = StoredButton::new(*styles.choose(rng).unwrap());
+                    button.label = Some(text(rng, 20));
+                    button.custom_id = Some(route(rng, index));
+                    button.disabled = rng.gen_bool(0.2);
+                    button
+                })
+                .collect();
+            StoredActionRow::Buttons(buttons)
+        } else {
+            let mut select = StoredSelect::new(SelectKind::String, route(rng, index));
+            select.placeholder = maybe_text(rng, 50);
+            for index in 0..rng.gen_range(1..=3) {
+                let mut option = StoredSelectOption::new(text(rng, 20), format!("v{}", index));
+                option.description = maybe_text(rng, 50);
+                select.options.push(option);
+            }
+            StoredActionRow::Select(select)
+        }
+    }
+
+    /// Generate a message with content, embeds and routed components.
+    fn message(rng: &mut StdRng) -> StoredMessage {
+        let mut message = StoredMessage::new();
+
+        if let Some(content) = maybe_text(rng, 200) {
+            message.push_content(content);
+        }
+
+        for _ in 0..rng.gen_range(0..3) {
+            message.push_embed(embed(rng));
+        }
+
+        let mut index = 0;
+        for _ in 0..rng.gen_range(0..3) {
+            message.push_action_row(action_row(rng, &mut index));
+        }
+
+        // Make sure the message isn't empty
+        if message.content.is_empty() && message.embeds.is_empty() {
+            message.push_content(text(rng, 200));
+        }
+
+        message
+    }
+
+    #[test]
+    fn round_trips_generated_messages() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..300 {
+            // Generate output with one or more messages
+            let mut output = VeaScriptOutput::new();
+            for _ in 0..rng.gen_range(1..=3) {
+                output.push_message(message(&mut rng));
+            }
+
+            // Check that the decompiled source builds into the same messages
+            let decompiled = output.to_veascript();
+            let rebuilt = build(&decompiled);
+            assert_eq!(
+                rebuilt.to_discord_json().unwrap(),
+                output.to_discord_json().unwrap(),
+                "\n{}",
+                decompiled
+            );
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use crate::decompilers::embed::{push_block_end, push_block_start, push_string, push_value, quote};
+use crate::enums::Keyword;
+use crate::structs::{StoredPoll, DEFAULT_POLL_DURATION};
+
+/// Decompile a stored poll into canonical VeaScript source.
+///
+/// Answers are written in their current order, so a shuffled poll decompiles to the order it was
+/// shuffled into.
+pub fn decompile_poll(poll: &StoredPoll) -> String {
+    decompile_poll_at(poll, 0)
+}
+
+/// Decompile a stored poll into canonical VeaScript source, nested at the given depth.
+pub(super) fn decompile_poll_at(poll: &StoredPoll, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Open poll block
+    push_block_start(&mut source, depth, Keyword::Poll);
+
+    // Add question
+    push_value(
+        &mut source,
+        depth + 1,
+        Keyword::Question,
+        quote(&poll.question),
+    );
+
+    // Add answers
+    for answer in &poll.answers {
+        push_block_start(&mut source, depth + 1, Keyword::Answer);
+        push_value(&mut source, depth + 2, Keyword::Label, quote(&answer.label));
+        push_string(&mut source, depth + 2, Keyword::Emoji, &answer.emoji);
+        push_block_end(&mut source, depth + 1);
+    }
+
+    // Add duration and multi-select, which are left out when they are the default
+    if poll.duration != DEFAULT_POLL_DURATION {
+        push_value(
+            &mut source,
+            depth + 1,
+            Keyword::Duration,
+            poll.duration.to_string(),
+        );
+    }
+
+    if poll.multi_select {
+        push_value(
+            &mut source,
+            depth + 1,
+            Keyword::MultiSelect,
+            "true".to_string(),
+        );
+    }
+
+    // Close poll block
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
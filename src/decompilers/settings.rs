@@ -0,0 +1,79 @@
+use crate::decompilers::embed::{push_block_end, push_block_start, push_value};
+use crate::enums::Keyword;
+use crate::structs::{MessageSettings, StoredAllowedMentions};
+
+/// Decompile message settings into canonical VeaScript source.
+pub fn decompile_settings(settings: &MessageSettings) -> String {
+    decompile_settings_at(settings, 0)
+}
+
+/// Decompile message settings into canonical VeaScript source, nested at the given depth.
+///
+/// Settings that are turned off are left out.
+pub(super) fn decompile_settings_at(settings: &MessageSettings, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Add settings that are turned on
+    push_block_start(&mut source, depth, Keyword::Settings);
+
+    for (set, keyword) in [
+        (settings.suppress_embeds, Keyword::SuppressEmbeds),
+        (settings.silent, Keyword::Silent),
+        (settings.ephemeral, Keyword::Ephemeral),
+        (settings.tts, Keyword::Tts),
+    ] {
+        if set {
+            push_value(&mut source, depth + 1, keyword, "true".to_string());
+        }
+    }
+
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
+
+/// Decompile allowed mentions into canonical VeaScript source.
+pub fn decompile_allowed_mentions(allowed_mentions: &StoredAllowedMentions) -> String {
+    decompile_allowed_mentions_at(allowed_mentions, 0)
+}
+
+/// Decompile allowed mentions into canonical VeaScript source, nested at the given depth.
+///
+/// Kinds of mentions that match the default are left out.
+pub(super) fn decompile_allowed_mentions_at(
+    allowed_mentions: &StoredAllowedMentions,
+    depth: usize,
+) -> String {
+    // Create source
+    let mut source = String::new();
+    let default = StoredAllowedMentions::default();
+
+    // Add kinds of mentions that differ from the default
+    push_block_start(&mut source, depth, Keyword::AllowedMentions);
+
+    for (allowed, default, keyword) in [
+        (allowed_mentions.users, default.users, Keyword::Users),
+        (allowed_mentions.roles, default.roles, Keyword::Roles),
+        (
+            allowed_mentions.everyone,
+            default.everyone,
+            Keyword::Everyone,
+        ),
+        (
+            allowed_mentions.replied_user,
+            default.replied_user,
+            Keyword::RepliedUser,
+        ),
+    ] {
+        if allowed != default {
+            push_value(&mut source, depth + 1, keyword, allowed.to_string());
+        }
+    }
+
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
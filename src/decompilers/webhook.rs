@@ -0,0 +1,35 @@
+use crate::decompilers::embed::{push_block_end, push_block_start, push_string};
+use crate::enums::Keyword;
+use crate::structs::StoredWebhook;
+
+/// Decompile a stored webhook identity into canonical VeaScript source.
+pub fn decompile_webhook(webhook: &StoredWebhook) -> String {
+    decompile_webhook_at(webhook, 0)
+}
+
+/// Decompile a stored webhook identity into canonical VeaScript source, nested at the given
+/// depth.
+pub(super) fn decompile_webhook_at(webhook: &StoredWebhook, depth: usize) -> String {
+    // Create source
+    let mut source = String::new();
+
+    // Add username, avatar URL and thread name
+    push_block_start(&mut source, depth, Keyword::Webhook);
+    push_string(&mut source, depth + 1, Keyword::Username, &webhook.username);
+    push_string(
+        &mut source,
+        depth + 1,
+        Keyword::AvatarUrl,
+        &webhook.avatar_url,
+    );
+    push_string(
+        &mut source,
+        depth + 1,
+        Keyword::ThreadName,
+        &webhook.thread_name,
+    );
+    push_block_end(&mut source, depth);
+
+    // Return source
+    source
+}
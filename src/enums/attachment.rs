@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+
+/// A VeaScript attachment component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum AttachmentComponent {
+    Filename(String),
+    File(String),
+    Content(String),
+    Description(String),
+    Spoiler(bool),
+    Unknown(String),
+}
@@ -0,0 +1,50 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The style of a message button.
+pub enum ButtonStyle {
+    /// A blurple button.
+    #[default]
+    Primary,
+
+    /// A grey button.
+    Secondary,
+
+    /// A green button.
+    Success,
+
+    /// A red button.
+    Danger,
+
+    /// A grey button that opens a URL instead of sending an interaction.
+    Link,
+}
+
+impl ButtonStyle {
+    /// All button styles.
+    pub const ALL: [ButtonStyle; 5] = [
+        ButtonStyle::Primary,
+        ButtonStyle::Secondary,
+        ButtonStyle::Success,
+        ButtonStyle::Danger,
+        ButtonStyle::Link,
+    ];
+
+    /// Get a button style from its name (e.g. `primary`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.name() == name.to_lowercase())
+    }
+
+    /// Get the name of the button style, as written in scripts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ButtonStyle::Primary => "primary",
+            ButtonStyle::Secondary => "secondary",
+            ButtonStyle::Success => "success",
+            ButtonStyle::Danger => "danger",
+            ButtonStyle::Link => "link",
+        }
+    }
+}
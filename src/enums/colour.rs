@@ -0,0 +1,33 @@
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// An enum representing a VeaScript colour expression.
+pub enum ColourExpr {
+    /// A hexadecimal or decimal colour value.
+    Value(i64),
+
+    /// A named colour, either a CSS colour name or a serenity colour preset.
+    Named(String),
+
+    /// A colour made from red, green and blue channels (from 0 to 255).
+    Rgb(f64, f64, f64),
+
+    /// A colour made from a hue (in degrees), saturation and lightness (as percentages).
+    Hsl(f64, f64, f64),
+
+    /// A colour lightened by a percentage.
+    Lighten(Box<Spanned<Self>>, f64),
+
+    /// A colour darkened by a percentage.
+    Darken(Box<Spanned<Self>>, f64),
+
+    /// Two colours mixed together, using the percentage of the first colour to include.
+    Mix(Box<Spanned<Self>>, Box<Spanned<Self>>, f64),
+
+    /// A random colour, chosen when the script is built.
+    Random,
+
+    /// A colour derived from a hash of some text, which is always the same for the same text.
+    Hash(String),
+}
@@ -0,0 +1,33 @@
+use crate::parsers::ParseError;
+use crate::structs::BuildError;
+use std::fmt::{Display, Formatter};
+
+/// An error produced while compiling a VeaScript script.
+#[derive(Debug)]
+pub enum CompileError {
+    /// The script couldn't be parsed.
+    Parse(Vec<ParseError>),
+
+    /// The script was parsed, but couldn't be built.
+    Build(BuildError),
+}
+
+impl From<BuildError> for CompileError {
+    fn from(value: BuildError) -> Self {
+        CompileError::Build(value)
+    }
+}
+
+impl Display for CompileError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CompileError::Parse(errors) => {
+                let messages: Vec<String> = errors.iter().map(ToString::to_string).collect();
+                write!(f, "{}", messages.join("\n"))
+            }
+            CompileError::Build(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for CompileError {}
@@ -0,0 +1,50 @@
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
+
+/// A VeaScript action row component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ActionRowComponent {
+    Button(Vec<Spanned<ButtonComponent>>),
+    Select(Vec<Spanned<SelectComponent>>),
+    Unknown(String),
+}
+
+/// A VeaScript button component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum ButtonComponent {
+    Style(String),
+    Label(String),
+    Emoji(String),
+    CustomId(String),
+    Url(String),
+    Disabled(bool),
+    Handler(String),
+    State(String),
+    Unknown(String),
+}
+
+/// A VeaScript select menu component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum SelectComponent {
+    Kind(String),
+    CustomId(String),
+    Placeholder(String),
+    MinValues(i32),
+    MaxValues(i32),
+    Disabled(bool),
+    Option(Vec<Spanned<SelectOptionComponent>>),
+    Handler(String),
+    State(String),
+    Unknown(String),
+}
+
+/// A VeaScript select menu option component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum SelectOptionComponent {
+    Label(String),
+    Value(String),
+    Description(String),
+    Emoji(String),
+    Default(bool),
+    Unknown(String),
+}
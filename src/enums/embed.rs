@@ -1,37 +1,45 @@
+use crate::enums::ColourExpr;
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
+
 /// A VeaScript embed component.
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum EmbedComponent {
-    Author(Vec<EmbedAuthorComponent>),
+    Author(Vec<Spanned<EmbedAuthorComponent>>),
     Title(String),
     Description(String),
-    Colour(i32),
-    Fields(Vec<Vec<EmbedFieldComponent>>),
+    Colour(Spanned<ColourExpr>),
+    Fields(Vec<Spanned<Vec<Spanned<EmbedFieldComponent>>>>),
     Image(String),
     Thumbnail(String),
     Url(String),
-    Footer(Vec<EmbedFooterComponent>),
+    Footer(Vec<Spanned<EmbedFooterComponent>>),
     Timestamp(i64),
+    Unknown(String),
 }
 
 /// A VeaScript embed author component.
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum EmbedAuthorComponent {
     Name(String),
     Url(String),
     IconUrl(String),
+    Unknown(String),
 }
 
 /// A VeaScript embed footer component.
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum EmbedFooterComponent {
     Text(String),
     IconUrl(String),
+    Unknown(String),
 }
 
 /// A VeaScript embed field component.
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum EmbedFieldComponent {
     Name(String),
     Value(String),
     Inline(bool),
+    Unknown(String),
 }
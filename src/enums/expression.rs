@@ -1,10 +1,23 @@
-use crate::enums::{EmbedComponent, MathExpr};
+use crate::enums::{
+    ActionRowComponent, AllowedMentionsComponent, AttachmentComponent, EmbedComponent, MathExpr,
+    PollComponent, SettingsComponent, WebhookComponent,
+};
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
 
 /// A VeaScript expression.
-#[derive(Debug)]
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub enum Expr {
     Text(String),
-    Math(MathExpr),
+    Math(Spanned<MathExpr>),
     Random(Vec<String>),
-    Embed(Vec<EmbedComponent>),
+    Embed(Vec<Spanned<EmbedComponent>>),
+    Message(Vec<Spanned<Expr>>),
+    Attachment(Vec<Spanned<AttachmentComponent>>),
+    ActionRow(Vec<Spanned<ActionRowComponent>>),
+    Handler(String, Vec<Spanned<Expr>>),
+    Poll(Vec<Spanned<PollComponent>>),
+    Settings(Vec<Spanned<SettingsComponent>>),
+    AllowedMentions(Vec<Spanned<AllowedMentionsComponent>>),
+    Webhook(Vec<Spanned<WebhookComponent>>),
 }
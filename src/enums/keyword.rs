@@ -0,0 +1,222 @@
+/// A VeaScript keyword, used to name blocks and tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Keyword {
+    Text,
+    Math,
+    Random,
+    Embed,
+    Message,
+    Attachment,
+    Author,
+    Title,
+    Description,
+    Colour,
+    Fields,
+    Field,
+    Image,
+    Thumbnail,
+    Url,
+    Footer,
+    Timestamp,
+    Name,
+    Value,
+    Inline,
+    IconUrl,
+    Filename,
+    File,
+    Content,
+    Spoiler,
+    ActionRow,
+    Button,
+    Select,
+    Option,
+    Style,
+    Label,
+    Emoji,
+    CustomId,
+    Disabled,
+    Type,
+    Placeholder,
+    MinValues,
+    MaxValues,
+    Default,
+    OnClick,
+    Handler,
+    State,
+    Poll,
+    Question,
+    Answer,
+    Duration,
+    MultiSelect,
+    Shuffle,
+    Settings,
+    SuppressEmbeds,
+    Silent,
+    Ephemeral,
+    Tts,
+    AllowedMentions,
+    Users,
+    Roles,
+    Everyone,
+    RepliedUser,
+    Webhook,
+    Username,
+    AvatarUrl,
+    ThreadName,
+}
+
+impl Keyword {
+    /// All VeaScript keywords.
+    pub const ALL: [Keyword; 62] = [
+        Keyword::Text,
+        Keyword::Math,
+        Keyword::Random,
+        Keyword::Embed,
+        Keyword::Message,
+        Keyword::Attachment,
+        Keyword::Author,
+        Keyword::Title,
+        Keyword::Description,
+        Keyword::Colour,
+        Keyword::Fields,
+        Keyword::Field,
+        Keyword::Image,
+        Keyword::Thumbnail,
+        Keyword::Url,
+        Keyword::Footer,
+        Keyword::Timestamp,
+        Keyword::Name,
+        Keyword::Value,
+        Keyword::Inline,
+        Keyword::IconUrl,
+        Keyword::Filename,
+        Keyword::File,
+        Keyword::Content,
+        Keyword::Spoiler,
+        Keyword::ActionRow,
+        Keyword::Button,
+        Keyword::Select,
+        Keyword::Option,
+        Keyword::Style,
+        Keyword::Label,
+        Keyword::Emoji,
+        Keyword::CustomId,
+        Keyword::Disabled,
+        Keyword::Type,
+        Keyword::Placeholder,
+        Keyword::MinValues,
+        Keyword::MaxValues,
+        Keyword::Default,
+        Keyword::OnClick,
+        Keyword::Handler,
+        Keyword::State,
+        Keyword::Poll,
+        Keyword::Question,
+        Keyword::Answer,
+        Keyword::Duration,
+        Keyword::MultiSelect,
+        Keyword::Shuffle,
+        Keyword::Settings,
+        Keyword::SuppressEmbeds,
+        Keyword::Silent,
+        Keyword::Ephemeral,
+        Keyword::Tts,
+        Keyword::AllowedMentions,
+        Keyword::Users,
+        Keyword::Roles,
+        Keyword::Everyone,
+        Keyword::RepliedUser,
+        Keyword::Webhook,
+        Keyword::Username,
+        Keyword::AvatarUrl,
+        Keyword::ThreadName,
+    ];
+
+    /// Get the canonical (English) spelling of the keyword.
+    pub fn canonical(&self) -> &'static str {
+        match self {
+            Keyword::Text => "text",
+            Keyword::Math => "math",
+            Keyword::Random => "random",
+            Keyword::Embed => "embed",
+            Keyword::Message => "message",
+            Keyword::Attachment => "attachment",
+            Keyword::Author => "author",
+            Keyword::Title => "title",
+            Keyword::Description => "description",
+            Keyword::Colour => "colour",
+            Keyword::Fields => "fields",
+            Keyword::Field => "field",
+            Keyword::Image => "image",
+            Keyword::Thumbnail => "thumbnail",
+            Keyword::Url => "url",
+            Keyword::Footer => "footer",
+            Keyword::Timestamp => "timestamp",
+            Keyword::Name => "name",
+            Keyword::Value => "value",
+            Keyword::Inline => "inline",
+            Keyword::IconUrl => "icon_url",
+            Keyword::Filename => "filename",
+            Keyword::File => "file",
+            Keyword::Content => "content",
+            Keyword::Spoiler => "spoiler",
+            Keyword::ActionRow => "actionrow",
+            Keyword::Button => "button",
+            Keyword::Select => "select",
+            Keyword::Option => "option",
+            Keyword::Style => "style",
+            Keyword::Label => "label",
+            Keyword::Emoji => "emoji",
+            Keyword::CustomId => "custom_id",
+            Keyword::Disabled => "disabled",
+            Keyword::Type => "type",
+            Keyword::Placeholder => "placeholder",
+            Keyword::MinValues => "min_values",
+            Keyword::MaxValues => "max_values",
+            Keyword::Default => "default",
+            Keyword::OnClick => "on_click",
+            Keyword::Handler => "handler",
+            Keyword::State => "state",
+            Keyword::Poll => "poll",
+            Keyword::Question => "question",
+            Keyword::Answer => "answer",
+            Keyword::Duration => "duration",
+            Keyword::MultiSelect => "multi_select",
+            Keyword::Shuffle => "shuffle",
+            Keyword::Settings => "settings",
+            Keyword::SuppressEmbeds => "suppress_embeds",
+            Keyword::Silent => "silent",
+            Keyword::Ephemeral => "ephemeral",
+            Keyword::Tts => "tts",
+            Keyword::AllowedMentions => "allowed_mentions",
+            Keyword::Users => "users",
+            Keyword::Roles => "roles",
+            Keyword::Everyone => "everyone",
+            Keyword::RepliedUser => "replied_user",
+            Keyword::Webhook => "webhook",
+            Keyword::Username => "username",
+            Keyword::AvatarUrl => "avatar_url",
+            Keyword::ThreadName => "thread_name",
+        }
+    }
+}
+
+/// A language that VeaScript keywords can be written in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Spanish,
+    French,
+}
+
+impl Language {
+    /// Get a language from its ISO 639-1 code (e.g. `en`).
+    pub fn from_code(code: &str) -> Option<Self> {
+        match code.to_lowercase().as_str() {
+            "en" => Some(Language::English),
+            "es" => Some(Language::Spanish),
+            "fr" => Some(Language::French),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The unit that text lengths are measured in when checking limits.
+pub enum LengthUnit {
+    /// Unicode code points, which is how Discord counts characters.
+    ///
+    /// An emoji such as 👍 is one code point, but an emoji with a skin tone or a character with
+    /// combining marks is several.
+    #[default]
+    CodePoints,
+
+    /// UTF-16 code units, which is how JavaScript counts characters.
+    ///
+    /// Characters outside of the Basic Multilingual Plane (such as most emoji) are two units.
+    Utf16,
+}
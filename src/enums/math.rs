@@ -1,21 +1,24 @@
-#[derive(Debug)]
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 /// An enum representing a VeaScript math expression.
 pub enum MathExpr {
     /// A standard 64-bit float.
     Num(f64),
 
     /// A negative operator to flip the sign of a number.
-    Neg(Box<Self>),
+    Neg(Box<Spanned<Self>>),
 
     /// An addition operator, to add two expressions together.
-    Add(Box<Self>, Box<Self>),
+    Add(Box<Spanned<Self>>, Box<Spanned<Self>>),
 
     /// A subtraction operator, to subtract one expression from another..
-    Sub(Box<Self>, Box<Self>),
+    Sub(Box<Spanned<Self>>, Box<Spanned<Self>>),
 
     /// A multiplication operator, to multiply two expressions together.
-    Mul(Box<Self>, Box<Self>),
+    Mul(Box<Spanned<Self>>, Box<Spanned<Self>>),
 
     /// A division operator, to divide two expressions.
-    Div(Box<Self>, Box<Self>),
+    Div(Box<Spanned<Self>>, Box<Spanned<Self>>),
 }
@@ -1,9 +1,35 @@
 // Import enum modules
+pub mod attachment;
+pub mod button_style;
+pub mod colour;
+pub mod compile_error;
+pub mod component;
 pub mod embed;
 pub mod expression;
+pub mod keyword;
+pub mod length_unit;
 pub mod math;
+pub mod overflow;
+pub mod poll;
+pub mod select_kind;
+pub mod settings;
+pub mod tag;
+pub mod webhook;
 
 // Re-export enums
+pub use attachment::*;
+pub use button_style::*;
+pub use colour::*;
+pub use compile_error::*;
+pub use component::*;
 pub use embed::*;
 pub use expression::*;
+pub use keyword::*;
+pub use length_unit::*;
 pub use math::*;
+pub use overflow::*;
+pub use poll::*;
+pub use select_kind::*;
+pub use settings::*;
+pub use tag::*;
+pub use webhook::*;
@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// What to do with message content that is longer than the content limit.
+pub enum Overflow {
+    /// Fail to build, or shorten the content when building leniently.
+    #[default]
+    Error,
+
+    /// Split the content into several messages at paragraph, line or word boundaries.
+    Split,
+
+    /// Keep as much content as fits in the message, and move the rest into a `.txt` attachment.
+    Attachment,
+}
@@ -0,0 +1,21 @@
+use crate::structs::Spanned;
+use serde::{Deserialize, Serialize};
+
+/// A VeaScript poll component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum PollComponent {
+    Question(String),
+    Answer(Vec<Spanned<PollAnswerComponent>>),
+    Duration(i32),
+    MultiSelect(bool),
+    Shuffle(bool),
+    Unknown(String),
+}
+
+/// A VeaScript poll answer component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum PollAnswerComponent {
+    Label(String),
+    Emoji(String),
+    Unknown(String),
+}
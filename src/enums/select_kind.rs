@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// The kind of values a select menu lets users choose from.
+pub enum SelectKind {
+    /// Options written in the script.
+    #[default]
+    String,
+
+    /// Members of the server.
+    User,
+
+    /// Roles in the server.
+    Role,
+
+    /// Channels in the server.
+    Channel,
+}
+
+impl SelectKind {
+    /// All select menu kinds.
+    pub const ALL: [SelectKind; 4] = [
+        SelectKind::String,
+        SelectKind::User,
+        SelectKind::Role,
+        SelectKind::Channel,
+    ];
+
+    /// Get a select menu kind from its name (e.g. `role`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::ALL
+            .into_iter()
+            .find(|kind| kind.name() == name.to_lowercase())
+    }
+
+    /// Get the name of the select menu kind, as written in scripts.
+    pub fn name(&self) -> &'static str {
+        match self {
+            SelectKind::String => "string",
+            SelectKind::User => "user",
+            SelectKind::Role => "role",
+            SelectKind::Channel => "channel",
+        }
+    }
+}
@@ -0,0 +1,21 @@
+use serde::{Deserialize, Serialize};
+
+/// A VeaScript message settings component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum SettingsComponent {
+    SuppressEmbeds(bool),
+    Silent(bool),
+    Ephemeral(bool),
+    Tts(bool),
+    Unknown(String),
+}
+
+/// A VeaScript allowed mentions component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum AllowedMentionsComponent {
+    Users(bool),
+    Roles(bool),
+    Everyone(bool),
+    RepliedUser(bool),
+    Unknown(String),
+}
@@ -0,0 +1,138 @@
+use crate::enums::Keyword;
+
+/// A VeaScript block that can contain tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TagBlock {
+    Embed,
+    Author,
+    Footer,
+    Field,
+    Attachment,
+    ActionRow,
+    Button,
+    Select,
+    SelectOption,
+    Poll,
+    PollAnswer,
+    Settings,
+    AllowedMentions,
+    Webhook,
+}
+
+impl TagBlock {
+    /// All blocks that can contain tags.
+    pub const ALL: [TagBlock; 14] = [
+        TagBlock::Embed,
+        TagBlock::Author,
+        TagBlock::Footer,
+        TagBlock::Field,
+        TagBlock::Attachment,
+        TagBlock::ActionRow,
+        TagBlock::Button,
+        TagBlock::Select,
+        TagBlock::SelectOption,
+        TagBlock::Poll,
+        TagBlock::PollAnswer,
+        TagBlock::Settings,
+        TagBlock::AllowedMentions,
+        TagBlock::Webhook,
+    ];
+
+    /// Get the name of the block, as used in error messages.
+    pub fn name(&self) -> &'static str {
+        match self {
+            TagBlock::Embed => "embed",
+            TagBlock::Author => "embed author",
+            TagBlock::Footer => "embed footer",
+            TagBlock::Field => "embed field",
+            TagBlock::Attachment => "attachment",
+            TagBlock::ActionRow => "action row",
+            TagBlock::Button => "button",
+            TagBlock::Select => "select menu",
+            TagBlock::SelectOption => "select option",
+            TagBlock::Poll => "poll",
+            TagBlock::PollAnswer => "poll answer",
+            TagBlock::Settings => "settings",
+            TagBlock::AllowedMentions => "allowed mentions",
+            TagBlock::Webhook => "webhook",
+        }
+    }
+
+    /// Get the tags that are valid inside the block.
+    pub fn tags(&self) -> &'static [Keyword] {
+        match self {
+            TagBlock::Embed => &[
+                Keyword::Author,
+                Keyword::Title,
+                Keyword::Description,
+                Keyword::Colour,
+                Keyword::Fields,
+                Keyword::Image,
+                Keyword::Thumbnail,
+                Keyword::Url,
+                Keyword::Footer,
+                Keyword::Timestamp,
+            ],
+            TagBlock::Author => &[Keyword::Name, Keyword::Url, Keyword::IconUrl],
+            TagBlock::Footer => &[Keyword::Text, Keyword::IconUrl],
+            TagBlock::Field => &[Keyword::Name, Keyword::Value, Keyword::Inline],
+            TagBlock::Attachment => &[
+                Keyword::Filename,
+                Keyword::File,
+                Keyword::Content,
+                Keyword::Description,
+                Keyword::Spoiler,
+            ],
+            TagBlock::ActionRow => &[Keyword::Button, Keyword::Select],
+            TagBlock::Button => &[
+                Keyword::Style,
+                Keyword::Label,
+                Keyword::Emoji,
+                Keyword::CustomId,
+                Keyword::Url,
+                Keyword::Disabled,
+                Keyword::Handler,
+                Keyword::State,
+            ],
+            TagBlock::Select => &[
+                Keyword::Type,
+                Keyword::CustomId,
+                Keyword::Placeholder,
+                Keyword::MinValues,
+                Keyword::MaxValues,
+                Keyword::Disabled,
+                Keyword::Option,
+                Keyword::Handler,
+                Keyword::State,
+            ],
+            TagBlock::SelectOption => &[
+                Keyword::Label,
+                Keyword::Value,
+                Keyword::Description,
+                Keyword::Emoji,
+                Keyword::Default,
+            ],
+            TagBlock::Poll => &[
+                Keyword::Question,
+                Keyword::Answer,
+                Keyword::Duration,
+                Keyword::MultiSelect,
+                Keyword::Shuffle,
+            ],
+            TagBlock::PollAnswer => &[Keyword::Label, Keyword::Emoji],
+            TagBlock::Settings => &[
+                Keyword::SuppressEmbeds,
+                Keyword::Silent,
+                Keyword::Ephemeral,
+                Keyword::Tts,
+            ],
+            TagBlock::AllowedMentions => &[
+                Keyword::Users,
+                Keyword::Roles,
+                Keyword::Everyone,
+                Keyword::RepliedUser,
+            ],
+            TagBlock::Webhook => &[Keyword::Username, Keyword::AvatarUrl, Keyword::ThreadName],
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use serde::{Deserialize, Serialize};
+
+/// A VeaScript webhook component.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum WebhookComponent {
+    Username(String),
+    AvatarUrl(String),
+    ThreadName(String),
+    Unknown(String),
+}
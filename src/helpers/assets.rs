@@ -0,0 +1,52 @@
+use std::fs;
+use std::path::{Component, Path};
+
+/// Read a file from an asset directory, refusing paths that lead outside of it.
+pub fn read(assets: &Path, file: &str, max_size: usize) -> Result<Vec<u8>, String> {
+    // Check that the path is relative and doesn't go up any directories
+    let relative = Path::new(file);
+
+    if !relative
+        .components()
+        .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(format!(
+            "The file path {} must be inside the asset directory, without any `..`.",
+            file
+        ));
+    }
+
+    // Resolve the file, following any links
+    let root = assets
+        .canonicalize()
+        .map_err(|_| "The asset directory could not be opened.".to_string())?;
+    let path = root.join(relative).canonicalize().map_err(|_| {
+        format!(
+            "The file {} could not be found in the asset directory.",
+            file
+        )
+    })?;
+
+    // Check that links haven't led outside of the asset directory
+    if !path.starts_with(&root) || !path.is_file() {
+        return Err(format!(
+            "The file {} could not be found in the asset directory.",
+            file
+        ));
+    }
+
+    // Check the file size before reading it
+    let size = fs::metadata(&path)
+        .map_err(|_| format!("The file {} could not be read.", file))?
+        .len();
+
+    if size > max_size as u64 {
+        return Err(format!(
+            "The file {} ({} bytes) is above the maximum attachment size of {} bytes.",
+            file, size, max_size
+        ));
+    }
+
+    // Read file
+    fs::read(&path).map_err(|_| format!("The file {} could not be read.", file))
+}
@@ -0,0 +1,314 @@
+use serenity::all::Colour;
+
+/// The largest colour value that can be used in an embed.
+pub const MAX_COLOUR: i64 = 0xFFFFFF;
+
+/// Serenity's colour presets, which are written in upper snake case (e.g. `BLURPLE`).
+pub const PRESET_COLOURS: &[(&str, Colour)] = &[
+    ("BLITZ_BLUE", Colour::BLITZ_BLUE),
+    ("BLUE", Colour::BLUE),
+    ("BLURPLE", Colour::BLURPLE),
+    ("DARK_BLUE", Colour::DARK_BLUE),
+    ("DARK_GOLD", Colour::DARK_GOLD),
+    ("DARK_GREEN", Colour::DARK_GREEN),
+    ("DARK_GREY", Colour::DARK_GREY),
+    ("DARK_MAGENTA", Colour::DARK_MAGENTA),
+    ("DARK_ORANGE", Colour::DARK_ORANGE),
+    ("DARK_PURPLE", Colour::DARK_PURPLE),
+    ("DARK_RED", Colour::DARK_RED),
+    ("DARK_TEAL", Colour::DARK_TEAL),
+    ("DARKER_GREY", Colour::DARKER_GREY),
+    ("FABLED_PINK", Colour::FABLED_PINK),
+    ("FADED_PURPLE", Colour::FADED_PURPLE),
+    ("FOOYOO", Colour::FOOYOO),
+    ("GOLD", Colour::GOLD),
+    ("KERBAL", Colour::KERBAL),
+    ("LIGHT_GREY", Colour::LIGHT_GREY),
+    ("LIGHTER_GREY", Colour::LIGHTER_GREY),
+    ("MAGENTA", Colour::MAGENTA),
+    ("MEIBE_PINK", Colour::MEIBE_PINK),
+    ("ORANGE", Colour::ORANGE),
+    ("PURPLE", Colour::PURPLE),
+    ("RED", Colour::RED),
+    ("ROHRKATZE_BLUE", Colour::ROHRKATZE_BLUE),
+    ("ROSEWATER", Colour::ROSEWATER),
+    ("TEAL", Colour::TEAL),
+];
+
+/// The CSS named colours, which are written in lowercase (e.g. `rebeccapurple`).
+pub const CSS_COLOURS: &[(&str, u32)] = &[
+    ("aliceblue", 0xF0F8FF),
+    ("antiquewhite", 0xFAEBD7),
+    ("aqua", 0x00FFFF),
+    ("aquamarine", 0x7FFFD4),
+    ("azure", 0xF0FFFF),
+    ("beige", 0xF5F5DC),
+    ("bisque", 0xFFE4C4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xFFEBCD),
+    ("blue", 0x0000FF),
+    ("blueviolet", 0x8A2BE2),
+    ("brown", 0xA52A2A),
+    ("burlywood", 0xDEB887),
+    ("cadetblue", 0x5F9EA0),
+    ("chartreuse", 0x7FFF00),
+    ("chocolate", 0xD2691E),
+    ("coral", 0xFF7F50),
+    ("cornflowerblue", 0x6495ED),
+    ("cornsilk", 0xFFF8DC),
+    ("crimson", 0xDC143C),
+    ("cyan", 0x00FFFF),
+    ("darkblue", 0x00008B),
+    ("darkcyan", 0x008B8B),
+    ("darkgoldenrod", 0xB8860B),
+    ("darkgray", 0xA9A9A9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xA9A9A9),
+    ("darkkhaki", 0xBDB76B),
+    ("darkmagenta", 0x8B008B),
+    ("darkolivegreen", 0x556B2F),
+    ("darkorange", 0xFF8C00),
+    ("darkorchid", 0x9932CC),
+    ("darkred", 0x8B0000),
+    ("darksalmon", 0xE9967A),
+    ("darkseagreen", 0x8FBC8F),
+    ("darkslateblue", 0x483D8B),
+    ("darkslategray", 0x2F4F4F),
+    ("darkslategrey", 0x2F4F4F),
+    ("darkturquoise", 0x00CED1),
+    ("darkviolet", 0x9400D3),
+    ("deeppink", 0xFF1493),
+    ("deepskyblue", 0x00BFFF),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1E90FF),
+    ("firebrick", 0xB22222),
+    ("floralwhite", 0xFFFAF0),
+    ("forestgreen", 0x228B22),
+    ("fuchsia", 0xFF00FF),
+    ("gainsboro", 0xDCDCDC),
+    ("ghostwhite", 0xF8F8FF),
+    ("gold", 0xFFD700),
+    ("goldenrod", 0xDAA520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xADFF2F),
+    ("grey", 0x808080),
+    ("honeydew", 0xF0FFF0),
+    ("hotpink", 0xFF69B4),
+    ("indianred", 0xCD5C5C),
+    ("indigo", 0x4B0082),
+    ("ivory", 0xFFFFF0),
+    ("khaki", 0xF0E68C),
+    ("lavender", 0xE6E6FA),
+    ("lavenderblush", 0xFFF0F5),
+    ("lawngreen", 0x7CFC00),
+    ("lemonchiffon", 0xFFFACD),
+    ("lightblue", 0xADD8E6),
+    ("lightcoral", 0xF08080),
+    ("lightcyan", 0xE0FFFF),
+    ("lightgoldenrodyellow", 0xFAFAD2),
+    ("lightgray", 0xD3D3D3),
+    ("lightgreen", 0x90EE90),
+    ("lightgrey", 0xD3D3D3),
+    ("lightpink", 0xFFB6C1),
+    ("lightsalmon", 0xFFA07A),
+    ("lightseagreen", 0x20B2AA),
+    ("lightskyblue", 0x87CEFA),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xB0C4DE),
+    ("lightyellow", 0xFFFFE0),
+    ("lime", 0x00FF00),
+    ("limegreen", 0x32CD32),
+    ("linen", 0xFAF0E6),
+    ("magenta", 0xFF00FF),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66CDAA),
+    ("mediumblue", 0x0000CD),
+    ("mediumorchid", 0xBA55D3),
+    ("mediumpurple", 0x9370DB),
+    ("mediumseagreen", 0x3CB371),
+    ("mediumslateblue", 0x7B68EE),
+    ("mediumspringgreen", 0x00FA9A),
+    ("mediumturquoise", 0x48D1CC),
+    ("mediumvioletred", 0xC71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xF5FFFA),
+    ("mistyrose", 0xFFE4E1),
+    ("moccasin", 0xFFE4B5),
+    ("navajowhite", 0xFFDEAD),
+    ("navy", 0x000080),
+    ("oldlace", 0xFDF5E6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6B8E23),
+    ("orange", 0xFFA500),
+    ("orangered", 0xFF4500),
+    ("orchid", 0xDA70D6),
+    ("palegoldenrod", 0xEEE8AA),
+    ("palegreen", 0x98FB98),
+    ("paleturquoise", 0xAFEEEE),
+    ("palevioletred", 0xDB7093),
+    ("papayawhip", 0xFFEFD5),
+    ("peachpuff", 0xFFDAB9),
+    ("peru", 0xCD853F),
+    ("pink", 0xFFC0CB),
+    ("plum", 0xDDA0DD),
+    ("powderblue", 0xB0E0E6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xFF0000),
+    ("rosybrown", 0xBC8F8F),
+    ("royalblue", 0x4169E1),
+    ("saddlebrown", 0x8B4513),
+    ("salmon", 0xFA8072),
+    ("sandybrown", 0xF4A460),
+    ("seagreen", 0x2E8B57),
+    ("seashell", 0xFFF5EE),
+    ("sienna", 0xA0522D),
+    ("silver", 0xC0C0C0),
+    ("skyblue", 0x87CEEB),
+    ("slateblue", 0x6A5ACD),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xFFFAFA),
+    ("springgreen", 0x00FF7F),
+    ("steelblue", 0x4682B4),
+    ("tan", 0xD2B48C),
+    ("teal", 0x008080),
+    ("thistle", 0xD8BFD8),
+    ("tomato", 0xFF6347),
+    ("turquoise", 0x40E0D0),
+    ("violet", 0xEE82EE),
+    ("wheat", 0xF5DEB3),
+    ("white", 0xFFFFFF),
+    ("whitesmoke", 0xF5F5F5),
+    ("yellow", 0xFFFF00),
+    ("yellowgreen", 0x9ACD32),
+];
+
+/// Look up a named colour.
+///
+/// Names written in upper snake case (e.g. `BLURPLE`) refer to serenity's colour presets, and all
+/// other names refer to CSS named colours, ignoring case.
+pub fn named(name: &str) -> Option<i32> {
+    // Check serenity presets for upper case names
+    if name.chars().all(|c| !c.is_lowercase()) {
+        if let Some((_, colour)) = PRESET_COLOURS.iter().find(|(preset, _)| *preset == name) {
+            return Some(colour.0 as i32);
+        }
+    }
+
+    // Check CSS colours
+    let name = name.to_lowercase();
+    CSS_COLOURS
+        .iter()
+        .find(|(css, _)| *css == name)
+        .map(|(_, colour)| *colour as i32)
+}
+
+/// Get all colour names, for suggestions.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    PRESET_COLOURS
+        .iter()
+        .map(|(name, _)| *name)
+        .chain(CSS_COLOURS.iter().map(|(name, _)| *name))
+}
+
+/// Create a colour from its red, green and blue channels.
+pub fn from_rgb(red: u8, green: u8, blue: u8) -> i32 {
+    ((red as i32) << 16) | ((green as i32) << 8) | blue as i32
+}
+
+/// Split a colour into its red, green and blue channels.
+pub fn to_rgb(colour: i32) -> (u8, u8, u8) {
+    ((colour >> 16) as u8, (colour >> 8) as u8, colour as u8)
+}
+
+/// Create a colour from its hue (in degrees), saturation and lightness (as fractions from 0 to 1).
+pub fn from_hsl(hue: f64, saturation: f64, lightness: f64) -> i32 {
+    // Get chroma and intermediate values
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let hue = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (hue % 2.0 - 1.0).abs());
+    let m = lightness - chroma / 2.0;
+
+    // Get channels before lightness adjustment
+    let (red, green, blue) = match hue as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    // Return colour
+    let channel = |value: f64| ((value + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+    from_rgb(channel(red), channel(green), channel(blue))
+}
+
+/// Split a colour into its hue (in degrees), saturation and lightness (as fractions from 0 to 1).
+pub fn to_hsl(colour: i32) -> (f64, f64, f64) {
+    // Get channels as fractions
+    let (red, green, blue) = to_rgb(colour);
+    let (red, green, blue) = (
+        red as f64 / 255.0,
+        green as f64 / 255.0,
+        blue as f64 / 255.0,
+    );
+
+    // Get lightness
+    let max = red.max(green).max(blue);
+    let min = red.min(green).min(blue);
+    let lightness = (max + min) / 2.0;
+
+    // Check for greys, which have no hue or saturation
+    let delta = max - min;
+    if delta == 0.0 {
+        return (0.0, 0.0, lightness);
+    }
+
+    // Get saturation and hue
+    let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+    let hue = if max == red {
+        60.0 * ((green - blue) / delta).rem_euclid(6.0)
+    } else if max == green {
+        60.0 * ((blue - red) / delta + 2.0)
+    } else {
+        60.0 * ((red - green) / delta + 4.0)
+    };
+
+    // Return hue, saturation and lightness
+    (hue, saturation, lightness)
+}
+
+/// Change the lightness of a colour by a fraction (from -1 to 1).
+pub fn adjust_lightness(colour: i32, amount: f64) -> i32 {
+    let (hue, saturation, lightness) = to_hsl(colour);
+    from_hsl(hue, saturation, (lightness + amount).clamp(0.0, 1.0))
+}
+
+/// Mix two colours, using the fraction (from 0 to 1) of the first colour to include.
+pub fn mix(first: i32, second: i32, weight: f64) -> i32 {
+    // Get channels
+    let (r1, g1, b1) = to_rgb(first);
+    let (r2, g2, b2) = to_rgb(second);
+
+    // Mix channels
+    let channel = |a: u8, b: u8| (a as f64 * weight + b as f64 * (1.0 - weight)).round() as u8;
+
+    // Return mixed colour
+    from_rgb(channel(r1, r2), channel(g1, g2), channel(b1, b2))
+}
+
+/// Get a deterministic colour for a piece of text, using its 32-bit FNV-1a hash.
+pub fn hash(text: &str) -> i32 {
+    // Hash text
+    let hash = text.bytes().fold(0x811C9DC5u32, |hash, byte| {
+        (hash ^ byte as u32).wrapping_mul(0x01000193)
+    });
+
+    // Return the lower 24 bits of the hash
+    (hash & 0xFFFFFF) as i32
+}
@@ -0,0 +1,38 @@
+/// Escape text so that it can be written inside a VeaScript string.
+pub fn string(text: &str) -> String {
+    // Create escaped string
+    let mut escaped = String::with_capacity(text.len() + 2);
+
+    // Escape characters that would end the string or be read as escapes
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            _ => escaped.push(c),
+        }
+    }
+
+    // Return escaped string
+    escaped
+}
+
+/// Escape text so that it can be written inside a VeaScript text block.
+pub fn text(text: &str) -> String {
+    // Create escaped text
+    let mut escaped = String::with_capacity(text.len());
+
+    // Escape characters that would end the block or be read as escapes
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '}' => escaped.push_str("\\}"),
+            _ => escaped.push(c),
+        }
+    }
+
+    // Return escaped text
+    escaped
+}
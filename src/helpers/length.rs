@@ -0,0 +1,142 @@
+use crate::enums::LengthUnit;
+
+/// Measure the length of some text in a given unit.
+pub fn measure(text: &str, unit: LengthUnit) -> usize {
+    match unit {
+        LengthUnit::CodePoints => text.chars().count(),
+        LengthUnit::Utf16 => text.encode_utf16().count(),
+    }
+}
+
+/// Shorten some text to a maximum length in a given unit, ending it with an ellipsis if any of it
+/// had to be removed.
+pub fn truncate(text: &str, max: usize, unit: LengthUnit) -> String {
+    // Return text unchanged if it already fits
+    if measure(text, unit) <= max {
+        return text.to_string();
+    }
+
+    // Add characters until there is only room left for the ellipsis
+    let mut truncated = String::new();
+    let mut length = 0;
+
+    for c in text.chars() {
+        // Get character length
+        let c_length = match unit {
+            LengthUnit::CodePoints => 1,
+            LengthUnit::Utf16 => c.len_utf16(),
+        };
+
+        // Stop if the character doesn't fit
+        if length + c_length + 1 > max {
+            break;
+        }
+
+        // Add character
+        truncated.push(c);
+        length += c_length;
+    }
+
+    // Return truncated text, with trailing whitespace removed before the ellipsis
+    let mut truncated = truncated.trim_end().to_string();
+    if max > 0 {
+        truncated.push('…');
+    }
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The default Discord limit for an embed title.
+    const TITLE_LIMIT: usize = 256;
+
+    #[test]
+    fn measures_emoji() {
+        // 👍 is one code point and a surrogate pair in UTF-16
+        assert_eq!(measure("👍", LengthUnit::CodePoints), 1);
+        assert_eq!(measure("👍", LengthUnit::Utf16), 2);
+
+        // 👍🏽 is the emoji and a skin tone modifier
+        assert_eq!(measure("👍🏽", LengthUnit::CodePoints), 2);
+        assert_eq!(measure("👍🏽", LengthUnit::Utf16), 4);
+    }
+
+    #[test]
+    fn measures_combining_marks() {
+        // "é" written as "e" and a combining acute accent
+        let text = "e\u{301}";
+        assert_eq!(measure(text, LengthUnit::CodePoints), 2);
+        assert_eq!(measure(text, LengthUnit::Utf16), 2);
+    }
+
+    #[test]
+    fn measures_cjk() {
+        // CJK characters in the Basic Multilingual Plane are one unit in both
+        assert_eq!(measure("日本語", LengthUnit::CodePoints), 3);
+        assert_eq!(measure("日本語", LengthUnit::Utf16), 3);
+
+        // 𠀋 is outside of the Basic Multilingual Plane
+        assert_eq!(measure("𠀋", LengthUnit::CodePoints), 1);
+        assert_eq!(measure("𠀋", LengthUnit::Utf16), 2);
+    }
+
+    #[test]
+    fn emoji_title_fits_code_points_but_not_utf16() {
+        let title = "😀".repeat(200);
+        assert!(measure(&title, LengthUnit::CodePoints) <= TITLE_LIMIT);
+        assert!(measure(&title, LengthUnit::Utf16) > TITLE_LIMIT);
+    }
+
+    #[test]
+    fn truncate_leaves_fitting_text_unchanged() {
+        for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+            assert_eq!(truncate("日本語", 3, unit), "日本語");
+        }
+    }
+
+    #[test]
+    fn truncate_fits_ellipsis_within_max() {
+        let title = "😀".repeat(200);
+
+        for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+            for max in [1, 2, 3, 10, 99, 100, 101, TITLE_LIMIT] {
+                let truncated = truncate(&title, max, unit);
+                assert!(measure(&truncated, unit) <= max, "{:?} at {}", unit, max);
+                assert_eq!(
+                    truncated.ends_with('…'),
+                    measure(&title, unit) > max,
+                    "{:?} at {}",
+                    unit,
+                    max
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn truncate_never_splits_surrogate_pairs() {
+        let text = "a😀👍🏽𠀋e\u{301}日本語".repeat(20);
+
+        for max in 1..measure(&text, LengthUnit::Utf16) {
+            // Every character kept is a whole character of the original text
+            let truncated = truncate(&text, max, LengthUnit::Utf16);
+            let kept = truncated.trim_end_matches('…');
+            assert!(text.starts_with(kept));
+
+            // The UTF-16 encoding has no unpaired surrogates
+            let units: Vec<u16> = truncated.encode_utf16().collect();
+            assert!(char::decode_utf16(units).all(|c| c.is_ok()));
+            assert!(measure(&truncated, LengthUnit::Utf16) <= max);
+        }
+    }
+
+    #[test]
+    fn truncate_counts_combining_marks_as_code_points() {
+        // The combining mark is counted as its own code point, so it can be cut off from its "e"
+        let truncated = truncate("abe\u{301}cd", 4, LengthUnit::CodePoints);
+        assert_eq!(truncated, "abe…");
+        assert_eq!(measure(&truncated, LengthUnit::CodePoints), 4);
+    }
+}
@@ -0,0 +1,19 @@
+/// Get the (1-based) line and column of a character offset in a piece of source.
+pub fn line_column(src: &str, offset: usize) -> (usize, usize) {
+    // Initialise line and column
+    let mut line = 1;
+    let mut column = 1;
+
+    // Iterate over characters before the offset
+    for c in src.chars().take(offset) {
+        if c == '\n' {
+            line += 1;
+            column = 1;
+        } else {
+            column += 1;
+        }
+    }
+
+    // Return line and column
+    (line, column)
+}
@@ -1,2 +1,9 @@
+pub mod assets;
+pub mod colour;
+pub mod escape;
 pub mod hexadecimal;
+pub mod length;
+pub mod location;
+pub mod split;
+pub mod suggestions;
 pub mod validation;
@@ -0,0 +1,395 @@
+use crate::enums::LengthUnit;
+use crate::helpers::length;
+
+/// The space kept free in each chunk for closing an open code fence and bold marker.
+const MARKDOWN_RESERVE: usize = "\n```".len() + "**".len();
+
+/// The smallest maximum length at which markdown is repaired across chunks. Below this, the
+/// reopened markdown could take up more space than each chunk frees.
+const MIN_REPAIR_LENGTH: usize = 64;
+
+/// The longest code block language that is carried over when a code block is reopened.
+const MAX_LANGUAGE_LENGTH: usize = 16;
+
+/// The longest mention, channel link or custom emoji that a hard cut will avoid breaking.
+const MAX_MENTION_LENGTH: usize = 100;
+
+/// Split some text into chunks that each fit within a maximum length in a given unit.
+///
+/// Text is split at paragraph breaks, then line breaks, then spaces, only cutting in the middle of
+/// a word when there is no other choice, and then before a link or mention rather than through it.
+/// Code blocks and bold text that are open at a split are closed at the end of the chunk and
+/// reopened at the start of the next one.
+pub fn split(text: &str, max: usize, unit: LengthUnit) -> Vec<String> {
+    // Create chunks
+    let mut chunks = Vec::new();
+    let mut rest = text.to_string();
+
+    // Take chunks from the start of the text until the rest fits
+    while length::measure(&rest, unit) > max {
+        let (chunk, remainder, reopen) = split_chunk(&rest, max, unit);
+
+        if !chunk.is_empty() {
+            chunks.push(chunk);
+        }
+
+        rest = reopen + &remainder;
+    }
+
+    // Add the rest of the text
+    if !rest.trim().is_empty() {
+        chunks.push(rest);
+    }
+
+    // Return chunks
+    chunks
+}
+
+/// Split the first chunk that fits within a maximum length from some text, returning the chunk
+/// and the remaining text.
+///
+/// The chunk has any open code block or bold text closed, while the remaining text is returned as
+/// it was written.
+pub fn split_first(text: &str, max: usize, unit: LengthUnit) -> (String, String) {
+    // Return text unchanged if it already fits
+    if length::measure(text, unit) <= max {
+        return (text.to_string(), String::new());
+    }
+
+    // Split chunk
+    let (chunk, remainder, _) = split_chunk(text, max, unit);
+    (chunk, remainder)
+}
+
+/// Split a chunk from the start of some text, returning the chunk with its markdown closed, the
+/// remaining text, and the markdown needed to reopen it.
+fn split_chunk(text: &str, max: usize, unit: LengthUnit) -> (String, String, String) {
+    // Leave room for closing markdown if the maximum is large enough to repair it
+    let repair = max >= MIN_REPAIR_LENGTH;
+    let budget = if repair { max - MARKDOWN_RESERVE } else { max };
+
+    // Find where the text stops fitting, then a boundary before it
+    let end = fitting_end(text, budget, unit);
+    let (cut, skip) = boundary(text, end);
+
+    // Split text at the boundary
+    let chunk = text[..cut].trim_end();
+    let remainder = text[cut + skip..].to_string();
+
+    // Return chunk unchanged if markdown isn't being repaired
+    if !repair {
+        return (chunk.to_string(), remainder, String::new());
+    }
+
+    // Close any markdown that is open at the end of the chunk, and reopen it in the remainder
+    let (fence, bold) = open_markdown(chunk);
+    let mut closed = chunk.to_string();
+    let mut reopen = String::new();
+
+    if bold {
+        reopen.push_str("**");
+    }
+
+    if let Some(language) = fence {
+        closed.push_str("\n```");
+        reopen.push_str(&format!("```{}\n", language));
+    }
+
+    if bold {
+        closed.push_str("**");
+    }
+
+    // Return chunk, remainder and reopening markdown
+    (closed, remainder, reopen)
+}
+
+/// Get the byte index at which some text stops fitting within a maximum length.
+fn fitting_end(text: &str, max: usize, unit: LengthUnit) -> usize {
+    // Add up character lengths until one doesn't fit
+    let mut length = 0;
+
+    for (index, c) in text.char_indices() {
+        // Get character length
+        length += match unit {
+            LengthUnit::CodePoints => 1,
+            LengthUnit::Utf16 => c.len_utf16(),
+        };
+
+        // Stop before the character that doesn't fit, always taking at least one character
+        if length > max {
+            return if index == 0 { c.len_utf8() } else { index };
+        }
+    }
+
+    // Return the end of the text
+    text.len()
+}
+
+/// Find the best place to split some text before a byte index, returning the index to cut at and
+/// the number of separator bytes to skip after it.
+fn boundary(text: &str, end: usize) -> (usize, usize) {
+    // Return the end if the whole text fits
+    if end >= text.len() {
+        return (end, 0);
+    }
+
+    // Get the part of the text that fits, only accepting boundaries in its second half so that
+    // chunks aren't left too short
+    let fitting = &text[..end];
+    let min = end / 2;
+
+    // Try paragraph breaks, then line breaks, then spaces
+    for separator in ["\n\n", "\n", " "] {
+        if let Some(index) = fitting.rfind(separator).filter(|index| *index >= min) {
+            return (index, separator.len());
+        }
+    }
+
+    // Cut before a link instead of through it
+    let word = fitting.rfind([' ', '\n']).map_or(0, |index| index + 1);
+    let link = ["https://", "http://"]
+        .into_iter()
+        .filter_map(|scheme| fitting[word..].find(scheme))
+        .min();
+
+    if let Some(index) = link.map(|index| word + index).filter(|index| *index > 0) {
+        return (index, 0);
+    }
+
+    // Cut before a mention, channel link or emoji instead of through it
+    if let Some(index) = fitting.rfind('<').filter(|index| *index > 0) {
+        if !fitting[index..].contains('>') && end - index <= MAX_MENTION_LENGTH {
+            return (index, 0);
+        }
+    }
+
+    // Cut in the middle of a word
+    (end, 0)
+}
+
+/// Find the markdown that is left open at the end of some text, returning the language of an open
+/// code block and whether bold text is open.
+fn open_markdown(text: &str) -> (Option<String>, bool) {
+    // Create markdown state
+    let mut fence: Option<String> = None;
+    let mut bold = false;
+    let mut rest = text;
+
+    // Toggle state at each code fence and bold marker
+    loop {
+        // Find the next code fence
+        let fence_index = rest.find("```");
+        let outside = match fence_index {
+            Some(index) => &rest[..index],
+            None => rest,
+        };
+
+        // Toggle bold for markers outside of code blocks
+        if fence.is_none() && outside.matches("**").count() % 2 == 1 {
+            bold = !bold;
+        }
+
+        // Stop if there are no more code fences
+        let Some(index) = fence_index else {
+            break;
+        };
+
+        // Open or close a code block
+        rest = &rest[index + 3..];
+        fence = match fence {
+            Some(_) => None,
+            None => Some(fence_language(rest)),
+        };
+    }
+
+    // Return markdown state
+    (fence, bold)
+}
+
+/// Get the language of a code block from the text after its opening fence, if it is short enough
+/// to carry over to the next chunk.
+fn fence_language(text: &str) -> String {
+    // Get the rest of the opening line, which is only a language if the line ends
+    let Some((line, _)) = text.split_once('\n') else {
+        return String::new();
+    };
+
+    // Check that the language looks like a language name
+    let valid = line.len() <= MAX_LANGUAGE_LENGTH
+        && line
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '_' | '#'));
+
+    // Return language
+    if valid {
+        line.to_string()
+    } else {
+        String::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+    use rand::{Rng, SeedableRng};
+
+    /// Mentions, channel links, emoji and URLs that should never be cut.
+    const UNBREAKABLE: &[&str] = &[
+        "<@123456789012345678>",
+        "<#876543210987654321>",
+        "<:blurple:112233445566778899>",
+        "https://example.com/a/long/path?query=value",
+        "http://example.org/😀/日本語",
+    ];
+
+    /// Markdown and separators mixed into generated text.
+    const MARKDOWN: &[&str] = &["**", "```rust\n", "\n```", "\n", "\n\n"];
+
+    /// Characters that words are made of, including ones that are two UTF-16 units.
+    const LETTERS: &[char] = &['a', 'b', 'é', '日', '😀', '𠀋'];
+
+    /// Generate text out of words, markdown and unbreakable parts, separated by spaces.
+    fn generate(rng: &mut StdRng, parts: usize) -> String {
+        let mut text = Vec::new();
+
+        for _ in 0..parts {
+            let part = match rng.gen_range(0..10) {
+                0 => UNBREAKABLE.choose(rng).unwrap().to_string(),
+                1 => MARKDOWN.choose(rng).unwrap().to_string(),
+                _ => (0..rng.gen_range(1..12))
+                    .map(|_| *LETTERS.choose(rng).unwrap())
+                    .collect(),
+            };
+            text.push(part);
+        }
+
+        text.join(" ")
+    }
+
+    #[test]
+    fn chunks_fit_within_max() {
+        let mut rng = StdRng::seed_from_u64(0);
+
+        for _ in 0..20 {
+            let text = generate(&mut rng, 150);
+
+            for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+                // Start at 2, the longest single character in UTF-16
+                for max in [
+                    2,
+                    3,
+                    10,
+                    MIN_REPAIR_LENGTH - 1,
+                    MIN_REPAIR_LENGTH,
+                    100,
+                    2000,
+                ] {
+                    for chunk in split(&text, max, unit) {
+                        assert!(
+                            length::measure(&chunk, unit) <= max,
+                            "{:?} at {}: {:?}",
+                            unit,
+                            max,
+                            chunk
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn never_cuts_mentions_or_urls() {
+        let mut rng = StdRng::seed_from_u64(1);
+
+        for _ in 0..20 {
+            let text = generate(&mut rng, 150);
+
+            for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+                for max in [MIN_REPAIR_LENGTH, 80, 100, 500] {
+                    let chunks = split(&text, max, unit);
+
+                    // Each unbreakable part is whole in some chunk as often as in the text
+                    for part in UNBREAKABLE {
+                        let count: usize =
+                            chunks.iter().map(|chunk| chunk.matches(part).count()).sum();
+                        assert_eq!(
+                            count,
+                            text.matches(part).count(),
+                            "{} was cut with {:?} at {}",
+                            part,
+                            unit,
+                            max
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn reopens_code_blocks_across_splits() {
+        let text = format!("```rust\n{}```", "let value = 1;\n".repeat(40));
+
+        for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+            let chunks = split(&text, 100, unit);
+            assert!(chunks.len() > 1);
+
+            for (index, chunk) in chunks.iter().enumerate() {
+                // Every chunk opens and closes its own code block
+                assert!(chunk.starts_with("```rust\n"), "{}: {:?}", index, chunk);
+                assert!(chunk.ends_with("```"), "{}: {:?}", index, chunk);
+                assert_eq!(chunk.matches("```").count(), 2, "{}: {:?}", index, chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn reopens_bold_text_across_splits() {
+        let text = format!("**{}**", "bold words ".repeat(40).trim_end());
+
+        for unit in [LengthUnit::CodePoints, LengthUnit::Utf16] {
+            let chunks = split(&text, 100, unit);
+            assert!(chunks.len() > 1);
+
+            for (index, chunk) in chunks.iter().enumerate() {
+                assert!(chunk.starts_with("**"), "{}: {:?}", index, chunk);
+                assert!(chunk.ends_with("**"), "{}: {:?}", index, chunk);
+                assert_eq!(chunk.matches("**").count(), 2, "{}: {:?}", index, chunk);
+            }
+        }
+    }
+
+    #[test]
+    fn reopens_bold_text_inside_code_blocks_in_order() {
+        let text = format!("**Bold ```\n{}```**", "code line\n".repeat(30));
+
+        for chunk in split(&text, 100, LengthUnit::CodePoints) {
+            // The code block is closed before the bold text, mirroring how they were opened
+            assert_eq!(chunk.matches("```").count(), 2, "{:?}", chunk);
+            assert!(chunk.ends_with("```**"), "{:?}", chunk);
+        }
+    }
+
+    #[test]
+    fn leaves_markdown_alone_below_repair_length() {
+        let text = format!("**{}**", "bold words ".repeat(20).trim_end());
+        let chunks = split(&text, MIN_REPAIR_LENGTH - 1, LengthUnit::CodePoints);
+
+        // Chunks are only split at spaces, so joining them gives the text back
+        assert!(chunks.len() > 1);
+        assert_eq!(chunks.join(" "), text);
+    }
+
+    #[test]
+    fn split_first_returns_remainder_as_written() {
+        let text = format!("**{}**", "bold words ".repeat(20).trim_end());
+        let (chunk, remainder) = split_first(&text, 100, LengthUnit::CodePoints);
+
+        assert!(chunk.ends_with("**"));
+        assert!(!remainder.starts_with("**"));
+        assert!(text.ends_with(&remainder));
+    }
+}
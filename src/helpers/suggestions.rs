@@ -0,0 +1,91 @@
+use crate::enums::TagBlock;
+use crate::structs::Keywords;
+
+/// Get the edit (Levenshtein) distance between two strings.
+pub fn edit_distance(a: &str, b: &str) -> usize {
+    // Collect characters of the second string
+    let b: Vec<char> = b.chars().collect();
+
+    // Create the previous row of distances
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    // Iterate over characters of the first string
+    for (i, a_char) in a.chars().enumerate() {
+        // Create the current row of distances
+        let mut current = vec![i + 1; b.len() + 1];
+
+        // Fill in the current row
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        // Move to the next row
+        previous = current;
+    }
+
+    // Return distance
+    previous[b.len()]
+}
+
+/// Find the closest candidate to a given word, if any is close enough to be a likely typo.
+pub fn closest<'a>(word: &str, candidates: impl IntoIterator<Item = &'a str>) -> Option<&'a str> {
+    // Get the maximum distance to suggest
+    let max_distance = (word.chars().count() / 2).clamp(1, 3);
+
+    // Find the closest candidate
+    candidates
+        .into_iter()
+        .map(|candidate| (edit_distance(word, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Create an error message for a tag that couldn't be parsed inside a block.
+pub fn unknown_tag(tag: &str, block: TagBlock, keywords: &Keywords) -> String {
+    // Get whether a block accepts a spelling of the tag
+    let accepts = |block: &TagBlock| {
+        block
+            .tags()
+            .iter()
+            .any(|keyword| keywords.spellings(*keyword).iter().any(|s| s == tag))
+    };
+
+    // Check if the tag is valid in this block, but had an invalid value
+    if accepts(&block) {
+        return format!("Invalid value provided for `#{}` in {}.", tag, block.name());
+    }
+
+    // Create base error
+    let mut error = format!("Unknown {} tag `#{}`.", block.name(), tag);
+
+    // Add closest known tag if there is one
+    let spellings = block
+        .tags()
+        .iter()
+        .flat_map(|keyword| keywords.spellings(*keyword))
+        .map(String::as_str);
+
+    if let Some(suggestion) = closest(tag, spellings) {
+        error.push_str(&format!(" Did you mean `#{}`?", suggestion));
+    }
+
+    // Add hint if the tag is valid in other blocks
+    let other_blocks: Vec<&str> = TagBlock::ALL
+        .iter()
+        .filter(|other| **other != block && accepts(other))
+        .map(|other| other.name())
+        .collect();
+
+    if !other_blocks.is_empty() {
+        error.push_str(&format!(
+            " `#{}` can only be used in: {}.",
+            tag,
+            other_blocks.join(", ")
+        ));
+    }
+
+    // Return error
+    error
+}
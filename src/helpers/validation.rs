@@ -1,11 +1,155 @@
+use serenity::all::Timestamp;
 use url::Url;
 
+/// The scheme used to refer to a message's attachments in embed URLs.
+pub const ATTACHMENT_SCHEME: &str = "attachment://";
+
 /// Validate a URL.
 pub fn url(url: &str, field: &str) -> Result<(), String> {
+    // Check if an attachment URL names a file
+    if let Some(name) = attachment_name(url) {
+        return filename(name)
+            .map_err(|_| format!("Invalid attachment URL provided for {}: {}.", field, url));
+    }
+
     // Check if URL is valid
-    if let Ok(_) = Url::parse(url) {
+    if Url::parse(url).is_ok() {
         Ok(())
     } else {
         Err(format!("Invalid URL provided for {}: {}.", field, url))
     }
 }
+
+/// The latest unix timestamp that can be written as a date (9999-12-31T23:59:59Z).
+pub const MAX_TIMESTAMP: i64 = 253_402_300_799;
+
+/// Validate an embed's unix timestamp.
+pub fn timestamp(timestamp: i64) -> Result<(), String> {
+    // Check if timestamp is not negative
+    if timestamp < 0 {
+        return Err(format!(
+            "Invalid embed timestamp ({}) - the timestamp for an embed must be greater than 0.",
+            timestamp
+        ));
+    }
+
+    // Check if timestamp can be represented as a date, before serenity converts it to
+    // milliseconds (which can overflow)
+    if timestamp > MAX_TIMESTAMP || Timestamp::from_unix_timestamp(timestamp).is_err() {
+        return Err(format!(
+            "Invalid embed timestamp ({}) - the timestamp is too far in the future to be a date.",
+            timestamp
+        ));
+    }
+
+    // Return success
+    Ok(())
+}
+
+/// The file extensions that Discord displays as images in embeds.
+pub const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "webp"];
+
+/// Check whether a URL's path ends with an image file extension.
+pub fn has_image_extension(url: &str) -> bool {
+    // Remove any query or fragment from the URL
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+
+    // Check extension
+    path.rsplit_once('.')
+        .map(|(_, extension)| IMAGE_EXTENSIONS.contains(&extension.to_lowercase().as_str()))
+        .unwrap_or(false)
+}
+
+/// Get the attachment file name that an `attachment://` URL refers to.
+pub fn attachment_name(url: &str) -> Option<&str> {
+    url.strip_prefix(ATTACHMENT_SCHEME)
+}
+
+/// Validate an attachment file name.
+pub fn filename(name: &str) -> Result<(), String> {
+    // Check if the name is empty
+    if name.trim().is_empty() {
+        return Err("An attachment's file name can't be empty.".to_string());
+    }
+
+    // Check if the name is a path
+    if name.contains(['/', '\\']) {
+        return Err(format!(
+            "An attachment's file name ({}) can't contain slashes.",
+            name
+        ));
+    }
+
+    // Return success
+    Ok(())
+}
+
+/// The words that Discord doesn't allow in webhook usernames.
+pub const FORBIDDEN_USERNAME_WORDS: &[&str] = &["discord", "clyde"];
+
+/// The names that Discord doesn't allow as webhook usernames.
+pub const FORBIDDEN_USERNAMES: &[&str] = &["everyone", "here"];
+
+/// Validate a webhook username.
+pub fn webhook_username(name: &str) -> Result<(), String> {
+    // Check if the name is empty
+    if name.trim().is_empty() {
+        return Err("A webhook's username can't be empty.".to_string());
+    }
+
+    // Check for forbidden names and words
+    let lowercase = name.to_lowercase();
+
+    if FORBIDDEN_USERNAMES.contains(&lowercase.trim()) {
+        return Err(format!("A webhook's username can't be {}.", name));
+    }
+
+    if let Some(word) = FORBIDDEN_USERNAME_WORDS
+        .iter()
+        .find(|word| lowercase.contains(*word))
+    {
+        return Err(format!(
+            "A webhook's username ({}) can't contain \"{}\".",
+            name, word
+        ));
+    }
+
+    // Return success
+    Ok(())
+}
+
+/// Validate the name of a component handler.
+pub fn handler_name(name: &str) -> Result<(), String> {
+    if !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-'))
+    {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid handler name: {:?}. Handler names can only contain letters, numbers, `_` and `-`.",
+            name
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accepts_timestamps_that_are_dates() {
+        assert!(timestamp(0).is_ok());
+        assert!(timestamp(13_454_321).is_ok());
+        assert!(timestamp(MAX_TIMESTAMP).is_ok());
+    }
+
+    #[test]
+    fn rejects_timestamps_that_are_not_dates() {
+        assert!(timestamp(-1).is_err());
+        assert!(timestamp(MAX_TIMESTAMP + 1).is_err());
+        assert!(timestamp(99_999_999_999_999).is_err());
+        assert!(timestamp(i64::MAX).is_err());
+    }
+}
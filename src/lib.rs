@@ -0,0 +1,301 @@
+use chumsky::prelude::*;
+use enums::Expr;
+use parsers::ParseError;
+use std::collections::HashSet;
+use structs::{
+    BuildError, BuildOptions, BuildWarning, ComponentRoute, InteractionContext, Keywords, Spanned,
+    StoredMessage,
+};
+
+// Import modules
+pub mod builders;
+pub mod decompilers;
+pub mod enums;
+pub mod helpers;
+pub mod parsers;
+pub mod runners;
+pub mod structs;
+
+// Re-export output
+pub use structs::VeaScriptOutput;
+
+/// Parse a VeaScript expression.
+pub fn parse_veascript() -> impl Parser<char, Vec<Spanned<Expr>>, Error = ParseError> {
+    parse_veascript_with(&Keywords::new())
+}
+
+/// Parse a VeaScript expression, using a given set of keywords.
+pub fn parse_veascript_with(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<Expr>>, Error = ParseError> {
+    // Try parsing random, then math
+    parsers::random(keywords)
+        .or(parsers::math(keywords))
+        .or(parsers::text(keywords))
+        .or(parsers::embed(keywords))
+        .or(parsers::attachment(keywords))
+        .or(parsers::action_row(keywords))
+        .or(parsers::poll(keywords))
+        .or(parsers::settings(keywords))
+        .or(parsers::allowed_mentions(keywords))
+        .or(parsers::webhook(keywords))
+        .or(parsers::message(keywords))
+        .or(parsers::handler(keywords))
+        .map_with_span(Spanned::new)
+        .padded()
+        .repeated()
+        .then_ignore(end())
+}
+
+/// Build VeaScript output from a slice of VeaScript expressions. The expressions are only
+/// borrowed, so a parsed script can be built many times without being copied.
+pub fn build_veascript(
+    script: &[Spanned<Expr>],
+    options: &BuildOptions,
+) -> Result<VeaScriptOutput, BuildError> {
+    // Create output
+    let mut output = VeaScriptOutput::new();
+
+    // Create random number generator
+    let mut rng = options.rng();
+
+    // Create message for expressions outside of message blocks
+    let mut message = StoredMessage::new();
+
+    // Collect handler names
+    let handlers = handler_names(script)?;
+
+    // Create settings, allowed mentions and a webhook identity for messages that don't set their
+    // own
+    let mut settings = None;
+    let mut allowed_mentions = None;
+    let mut webhook = None;
+
+    // Iterate over expressions
+    for expression in script {
+        match &expression.node {
+            Expr::Handler(..) => {
+                // Handlers are only built when their components are used
+            }
+            Expr::Settings(components) => {
+                // Check if settings have already been set
+                if settings.is_some() {
+                    return Err(BuildError::new(
+                        expression.span,
+                        "A script can only have one top-level #settings block.",
+                    ));
+                }
+
+                settings = Some(builders::settings(
+                    components,
+                    options,
+                    &mut output.warnings,
+                )?);
+            }
+            Expr::AllowedMentions(components) => {
+                // Check if allowed mentions have already been set
+                if allowed_mentions.is_some() {
+                    return Err(BuildError::new(
+                        expression.span,
+                        "A script can only have one top-level #allowed_mentions block.",
+                    ));
+                }
+
+                allowed_mentions = Some(builders::allowed_mentions(
+                    components,
+                    options,
+                    &mut output.warnings,
+                )?);
+            }
+            Expr::Webhook(components) => {
+                // Check if the webhook identity has already been set
+                if webhook.is_some() {
+                    return Err(BuildError::new(
+                        expression.span,
+                        "A script can only have one top-level #webhook block.",
+                    ));
+                }
+
+                webhook = Some(builders::webhook(
+                    components,
+                    expression.span,
+                    options,
+                    &mut output.warnings,
+                )?);
+            }
+            Expr::Message(expressions) => {
+                // Send any expressions before the message block as their own message
+                if !message.is_empty() {
+                    output.push_message(std::mem::take(&mut message));
+                }
+
+                // Build message block
+                let block =
+                    builders::message(expressions, &mut rng, options, &mut output.warnings)?;
+
+                // Add message, unless there is nothing to send
+                if block.is_empty() {
+                    output.push_warning(BuildWarning::new(
+                        expression.span,
+                        "This message is empty, so it was not sent.",
+                    ));
+                } else {
+                    output.push_message(block);
+                }
+            }
+            _ => {
+                builders::expression(
+                    expression,
+                    &mut message,
+                    &mut rng,
+                    options,
+                    &mut output.warnings,
+                )?;
+            }
+        }
+    }
+
+    // Send any remaining expressions as the last message
+    if !message.is_empty() || output.messages.is_empty() {
+        output.push_message(message);
+    }
+
+    // Point attachment URLs at the files they refer to, check component routes, and apply the
+    // script's settings, allowed mentions and webhook identity
+    for message in &mut output.messages {
+        message.link_attachments()?;
+        check_routes(message, &handlers, options)?;
+        message.settings = message.settings.or(settings);
+        message.allowed_mentions = message.allowed_mentions.or(allowed_mentions);
+        message.webhook = message.webhook.take().or_else(|| webhook.clone());
+    }
+
+    // Split or move content over the content limit
+    output.overflow(options.overflow, &options.limits);
+
+    // Fit output to message limits if lenient
+    if options.lenient {
+        output.fit(&options.limits);
+    }
+
+    // Check message limits
+    output.validate(&options.limits)?;
+
+    // Return output
+    Ok(output)
+}
+
+/// Render a handler block from a VeaScript script for a component interaction, producing the
+/// message that the interaction's message is updated to.
+///
+/// Content over the limit is handled with the options' overflow setting, as it is when building.
+/// Splitting can produce more than one message, in which case the first updates the message and
+/// the rest can be sent as follow-ups.
+pub fn render_handler(
+    script: &[Spanned<Expr>],
+    context: &InteractionContext,
+    options: &BuildOptions,
+) -> Result<VeaScriptOutput, BuildError> {
+    // Create output
+    let mut output = VeaScriptOutput::new();
+
+    // Create random number generator
+    let mut rng = options.rng();
+
+    // Collect handler names
+    let handlers = handler_names(script)?;
+
+    // Find handler
+    let expressions = script
+        .iter()
+        .find_map(|expression| match &expression.node {
+            Expr::Handler(name, expressions) if *name == context.route.handler => Some(expressions),
+            _ => None,
+        })
+        .ok_or_else(|| {
+            BuildError::unspanned(format!(
+                "This script has no handler named {:?}.",
+                context.route.handler
+            ))
+        })?;
+
+    // Build handler into a message
+    let mut message = builders::message(expressions, &mut rng, options, &mut output.warnings)?;
+
+    // Fill in the interaction context
+    message.substitute(context);
+
+    // Point attachment URLs at the files they refer to, and check component routes
+    message.link_attachments()?;
+    check_routes(&message, &handlers, options)?;
+    output.push_message(message);
+
+    // Split or move content over the content limit
+    output.overflow(options.overflow, &options.limits);
+
+    // Fit output to message limits if lenient
+    if options.lenient {
+        output.fit(&options.limits);
+    }
+
+    // Check message limits
+    output.validate(&options.limits)?;
+
+    // Return output
+    Ok(output)
+}
+
+/// Collect the names of the handler blocks in a script, checking that each is valid and unique.
+fn handler_names(script: &[Spanned<Expr>]) -> Result<HashSet<String>, BuildError> {
+    let mut names = HashSet::new();
+
+    for expression in script {
+        if let Expr::Handler(name, _) = &expression.node {
+            // Check handler name
+            helpers::validation::handler_name(name)
+                .map_err(|error| BuildError::new(expression.span, error))?;
+
+            // Check if handler has already been defined
+            if !names.insert(name.clone()) {
+                return Err(BuildError::new(
+                    expression.span,
+                    format!("There is already a handler named {:?}.", name),
+                ));
+            }
+        }
+    }
+
+    // Return names
+    Ok(names)
+}
+
+/// Check that every component routed to this script points at one of its handlers.
+fn check_routes(
+    message: &StoredMessage,
+    handlers: &HashSet<String>,
+    options: &BuildOptions,
+) -> Result<(), BuildError> {
+    for row in &message.components {
+        for custom_id in row.custom_ids() {
+            // Skip components that aren't routed to this script
+            let Some(route) = ComponentRoute::decode(custom_id) else {
+                continue;
+            };
+
+            if options.script_id.as_deref() != Some(route.script_id.as_str()) {
+                continue;
+            }
+
+            // Check that the handler exists
+            if !handlers.contains(&route.handler) {
+                return Err(BuildError::unspanned(format!(
+                    "This script has no handler named {:?}. Add one with #on_click {:?} {{ ... }}.",
+                    route.handler, route.handler
+                )));
+            }
+        }
+    }
+
+    // Return success
+    Ok(())
+}
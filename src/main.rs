@@ -1,110 +1,85 @@
-#![allow(unused)]
-use chumsky::chain::Chain;
-use chumsky::prelude::*;
-use enums::Expr;
-use rand::seq::SliceRandom;
-use rand::thread_rng;
-use serenity::all::Embed;
-use structs::StoredEmbed;
-
-// Import modules
-mod builders;
-mod enums;
-mod helpers;
-mod parsers;
-mod runners;
-mod structs;
+use veascript::enums::Language;
+use veascript::helpers::location;
+use veascript::structs::{BuildOptions, Keywords, MessagePayload, VeaScriptParser};
+use veascript::{build_veascript, VeaScriptOutput};
 
 fn main() {
     // Read .vs file
-    let src = std::fs::read_to_string(std::env::args().nth(1).unwrap()).unwrap();
+    let path = std::env::args().nth(1).unwrap();
+    let src = std::fs::read_to_string(&path).unwrap();
 
-    // Parse script
-    match parse_veascript().parse(src) {
-        Ok(script) => {
-            // Build script
-            match build_veascript(script) {
-                Ok(output) => {
-                    // Display output
-                    println!(
-                        "Content:\n{}\n\nEmbeds:\n{:?}",
-                        output.content, output.embeds
-                    )
-                }
-                Err(error) => {
-                    println!("Build error: {}", error)
-                }
-            }
-        }
-        Err(error) => {
-            println!("Parsing error: {:?}", error);
+    // Convert Discord message JSON files into VeaScript source
+    if path.ends_with(".json") {
+        match VeaScriptOutput::from_discord_json(&src) {
+            Ok(output) => print!("{}", output.to_veascript()),
+            Err(error) => println!("Import error: {}", error),
         }
+        return;
     }
-}
 
-#[derive(Debug)]
-pub struct VeaScriptOutput {
-    /// The content produced by a VeaScript block.
-    pub content: String,
+    // Get keyword language, defaulting to English
+    let language = std::env::args()
+        .nth(2)
+        .map(|code| Language::from_code(&code).expect("Unknown keyword language code"))
+        .unwrap_or(Language::English);
 
-    /// The embeds produced by a VeaScript block.
-    pub embeds: Vec<StoredEmbed>,
-}
+    // Read attached files from the script's directory
+    let assets = std::path::Path::new(&path)
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(std::path::Path::new("."));
+    let mut options = BuildOptions::new().assets(assets);
 
-impl VeaScriptOutput {
-    /// Create a new output struct.
-    pub fn new() -> Self {
-        Self {
-            content: String::new(),
-            embeds: Vec::new(),
-        }
+    // Route component handlers to the script by its file name
+    if let Some(stem) = std::path::Path::new(&path).file_stem() {
+        options = options.script_id(stem.to_string_lossy());
     }
 
-    /// Push to the output's content.
-    pub fn push_content(&mut self, content: impl Into<String>) {
-        self.content.push_str(&content.into());
-    }
+    // Create parser
+    let parser = VeaScriptParser::new(&Keywords::for_language(language));
 
-    /// Add an embed to the output.
-    pub fn push_embed(&mut self, embed: StoredEmbed) {
-        self.embeds.push(embed);
-    }
-}
-
-/// Parse a VeaScript expression.
-pub fn parse_veascript() -> impl Parser<char, Vec<Expr>, Error = Simple<char>> {
-    // Try parsing random, then math
-    parsers::random()
-        .or(parsers::math())
-        .or(parsers::text())
-        .or(parsers::embed())
-        .repeated()
-        .then_ignore(end())
-}
-
-/// Build VeaScript output from a vector of VeaScript expressions.
-pub fn build_veascript(script: Vec<Expr>) -> Result<VeaScriptOutput, String> {
-    // Create output
-    let mut output = VeaScriptOutput::new();
+    // Parse script
+    match parser.parse(0, &src) {
+        Ok(script) => {
+            // Build script
+            match build_veascript(&script, &options) {
+                Ok(output) => {
+                    // Display each message as Discord message JSON
+                    for message in &output.messages {
+                        match MessagePayload::try_from(message) {
+                            Ok(payload) => {
+                                println!("{}", serde_json::to_string_pretty(&payload).unwrap())
+                            }
+                            Err(error) => println!("Export error: {}", error),
+                        }
+                    }
 
-    // Iterate over expressions
-    for expression in script {
-        match expression {
-            Expr::Math(expression) => {
-                output.push_content(&runners::eval(&expression).to_string());
-            }
-            Expr::Random(options) => {
-                output.push_content(options.choose(&mut thread_rng()).unwrap());
-            }
-            Expr::Text(text) => {
-                output.push_content(&text);
+                    // Display each warning with its location
+                    for warning in output.warnings {
+                        match warning.span {
+                            Some(span) => {
+                                let (line, column) = location::line_column(&src, span.start);
+                                eprintln!("Warning at {}:{}: {}", line, column, warning);
+                            }
+                            None => eprintln!("Warning: {}", warning),
+                        }
+                    }
+                }
+                Err(error) => match error.span {
+                    Some(span) => {
+                        let (line, column) = location::line_column(&src, span.start);
+                        println!("Build error at {}:{}: {}", line, column, error);
+                    }
+                    None => println!("Build error: {}", error),
+                },
             }
-            Expr::Embed(components) => {
-                output.push_embed(builders::embed(components)?);
+        }
+        Err(errors) => {
+            // Display each parsing error with its location
+            for error in errors {
+                let (line, column) = location::line_column(&src, error.span().start);
+                println!("Parsing error at {}:{}: {}", line, column, error);
             }
         }
     }
-
-    // Return output
-    Ok(output)
 }
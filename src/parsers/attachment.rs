@@ -0,0 +1,78 @@
+use crate::enums::{AttachmentComponent, Expr, Keyword, TagBlock};
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse an attachment into a VeaScript attachment.
+pub fn parse_attachment(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Return attachment parser
+    parse_attachment_raw(keywords).map(Expr::Attachment)
+}
+
+/// Parse an attachment into a vector of attachment components.
+pub fn parse_attachment_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<AttachmentComponent>>, Error = ParseError> {
+    // Create attachment parser
+    let attachment_parser = parse_attachment_component(keywords)
+        .repeated()
+        .delimited_by(
+            parsers::block_start(keywords, Keyword::Attachment),
+            just("}"),
+        )
+        .collect();
+
+    // Return attachment parser
+    attachment_parser
+}
+
+/// Parse an attachment component.
+pub fn parse_attachment_component(
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<AttachmentComponent>, Error = ParseError> {
+    // Create attachment component parser
+    let component_parser =
+        parse_attachment_string_field(keywords, Keyword::Filename, AttachmentComponent::Filename)
+            .or(parse_attachment_string_field(
+                keywords,
+                Keyword::File,
+                AttachmentComponent::File,
+            ))
+            .or(parse_attachment_string_field(
+                keywords,
+                Keyword::Content,
+                AttachmentComponent::Content,
+            ))
+            .or(parse_attachment_string_field(
+                keywords,
+                Keyword::Description,
+                AttachmentComponent::Description,
+            ))
+            .or(parse_attachment_spoiler(keywords))
+            .or(parsers::unknown_tag(keywords, TagBlock::Attachment)
+                .map(AttachmentComponent::Unknown));
+
+    // Return attachment component parser
+    component_parser.map_with_span(Spanned::new).padded()
+}
+
+/// Parse a string field for an attachment.
+pub fn parse_attachment_string_field(
+    keywords: &Keywords,
+    keyword: Keyword,
+    component: impl Fn(String) -> AttachmentComponent,
+) -> impl Parser<char, AttachmentComponent, Error = ParseError> {
+    parsers::string()
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
+        .map(component)
+}
+
+/// Parse an attachment's spoiler setting.
+pub fn parse_attachment_spoiler(
+    keywords: &Keywords,
+) -> impl Parser<char, AttachmentComponent, Error = ParseError> {
+    parsers::boolean()
+        .delimited_by(parsers::field_start(keywords, Keyword::Spoiler), just(','))
+        .map(AttachmentComponent::Spoiler)
+}
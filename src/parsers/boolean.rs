@@ -1,7 +1,8 @@
+use crate::parsers::ParseError;
 use chumsky::prelude::*;
 
 /// Parse a boolean.
-pub fn parse_bool() -> impl Parser<char, bool, Error = Simple<char>> {
+pub fn parse_bool() -> impl Parser<char, bool, Error = ParseError> {
     just("true")
         .or(just("false"))
         .padded()
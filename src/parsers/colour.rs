@@ -0,0 +1,106 @@
+use crate::enums::ColourExpr;
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::Spanned;
+use chumsky::prelude::*;
+
+/// The maximum depth that colour functions (such as `mix`) can be nested.
+pub const MAX_COLOUR_NESTING: usize = 16;
+
+/// Parse a colour expression, such as `#5865F2`, `BLURPLE`, `rgb(88, 101, 242)` or
+/// `lighten(teal, 10%)`.
+pub fn parse_colour() -> impl Parser<char, Spanned<ColourExpr>, Error = ParseError> {
+    parsers::nested(MAX_COLOUR_NESTING, |colour| {
+        // Create percentage parser, where the percent sign is optional
+        let percentage = || parsers::float().then_ignore(just('%').or_not()).padded();
+
+        // Create function parser, which parses a name followed by its arguments in parentheses
+        let function = |names: &'static [&'static str]| {
+            choice(names.iter().map(|name| just(*name)).collect::<Vec<_>>())
+                .then_ignore(just('(').padded())
+        };
+
+        // Create argument separator parser
+        let comma = || just(',').padded();
+
+        // Create literal value parser
+        let value = parsers::hex()
+            .or(parsers::int64())
+            .map(ColourExpr::Value)
+            .padded();
+
+        // Create rgb parser
+        let rgb = function(&["rgb"])
+            .ignore_then(parsers::float())
+            .then_ignore(comma())
+            .then(parsers::float())
+            .then_ignore(comma())
+            .then(parsers::float())
+            .then_ignore(just(')'))
+            .map(|((red, green), blue)| ColourExpr::Rgb(red, green, blue));
+
+        // Create hsl parser
+        let hsl = function(&["hsl"])
+            .ignore_then(parsers::float())
+            .then_ignore(just("deg").or_not().padded())
+            .then_ignore(comma())
+            .then(percentage())
+            .then_ignore(comma())
+            .then(percentage())
+            .then_ignore(just(')'))
+            .map(|((hue, saturation), lightness)| ColourExpr::Hsl(hue, saturation, lightness));
+
+        // Create lighten and darken parsers
+        let lighten = function(&["lighten"])
+            .ignore_then(colour.clone())
+            .then_ignore(comma())
+            .then(percentage())
+            .then_ignore(just(')'))
+            .map(|(colour, amount)| ColourExpr::Lighten(Box::new(colour), amount));
+
+        let darken = function(&["darken"])
+            .ignore_then(colour.clone())
+            .then_ignore(comma())
+            .then(percentage())
+            .then_ignore(just(')'))
+            .map(|(colour, amount)| ColourExpr::Darken(Box::new(colour), amount));
+
+        // Create mix parser, where the weight defaults to an even mix
+        let mix = function(&["mix"])
+            .ignore_then(colour.clone())
+            .then_ignore(comma())
+            .then(colour)
+            .then(comma().ignore_then(percentage()).or_not())
+            .then_ignore(just(')'))
+            .map(|((first, second), weight)| {
+                ColourExpr::Mix(Box::new(first), Box::new(second), weight.unwrap_or(50.0))
+            });
+
+        // Create random colour parser
+        let random = function(&["random_colour", "random_color"])
+            .ignore_then(just(')'))
+            .to(ColourExpr::Random);
+
+        // Create hash colour parser
+        let hash = function(&["hash_colour", "hash_color"])
+            .ignore_then(parsers::string())
+            .then_ignore(just(')'))
+            .map(ColourExpr::Hash);
+
+        // Create named colour parser
+        let named = text::ident().map(ColourExpr::Named);
+
+        // Return colour parser, trying functions before names so that they aren't read as names
+        value
+            .or(rgb)
+            .or(hsl)
+            .or(lighten)
+            .or(darken)
+            .or(mix)
+            .or(random)
+            .or(hash)
+            .or(named)
+            .map_with_span(Spanned::new)
+            .padded()
+    })
+}
@@ -0,0 +1,227 @@
+use crate::enums::{
+    ActionRowComponent, ButtonComponent, Expr, Keyword, SelectComponent, SelectOptionComponent,
+    TagBlock,
+};
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse an action row into a VeaScript action row.
+pub fn parse_action_row(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Return action row parser
+    parse_action_row_raw(keywords).map(Expr::ActionRow)
+}
+
+/// Parse an action row into a vector of action row components.
+pub fn parse_action_row_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<ActionRowComponent>>, Error = ParseError> {
+    // Create action row component parser
+    let component_parser = parse_button(keywords)
+        .or(parse_select(keywords))
+        .or(parsers::unknown_tag(keywords, TagBlock::ActionRow).map(ActionRowComponent::Unknown))
+        .map_with_span(Spanned::new)
+        .padded();
+
+    // Return action row parser
+    component_parser
+        .repeated()
+        .delimited_by(
+            parsers::block_start(keywords, Keyword::ActionRow),
+            just("}"),
+        )
+        .collect()
+}
+
+/// Parse a field with a string value, such as `#label: "Click me",`.
+pub(super) fn parse_string_value<T>(
+    keywords: &Keywords,
+    keyword: Keyword,
+    component: impl Fn(String) -> T,
+) -> impl Parser<char, T, Error = ParseError> {
+    parsers::string()
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
+        .map(component)
+}
+
+/// Parse a field with a name as its value, such as `#style: primary,`.
+pub(super) fn parse_name_value<T>(
+    keywords: &Keywords,
+    keyword: Keyword,
+    component: impl Fn(String) -> T,
+) -> impl Parser<char, T, Error = ParseError> {
+    text::ident()
+        .padded()
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
+        .map(component)
+}
+
+/// Parse a field with a boolean value, such as `#disabled: true,`.
+pub(super) fn parse_bool_value<T>(
+    keywords: &Keywords,
+    keyword: Keyword,
+    component: impl Fn(bool) -> T,
+) -> impl Parser<char, T, Error = ParseError> {
+    parsers::boolean()
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
+        .map(component)
+}
+
+/// Parse a field with a number value, such as `#max_values: 3,`.
+pub(super) fn parse_number_value<T>(
+    keywords: &Keywords,
+    keyword: Keyword,
+    component: impl Fn(i32) -> T,
+) -> impl Parser<char, T, Error = ParseError> {
+    parsers::int32()
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
+        .map(component)
+}
+
+/// Parse a button into a VeaScript action row component.
+pub fn parse_button(
+    keywords: &Keywords,
+) -> impl Parser<char, ActionRowComponent, Error = ParseError> {
+    // Create button component parser
+    let component_parser = parse_name_value(keywords, Keyword::Style, ButtonComponent::Style)
+        .or(parse_string_value(
+            keywords,
+            Keyword::Label,
+            ButtonComponent::Label,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::Emoji,
+            ButtonComponent::Emoji,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::CustomId,
+            ButtonComponent::CustomId,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::Url,
+            ButtonComponent::Url,
+        ))
+        .or(parse_bool_value(
+            keywords,
+            Keyword::Disabled,
+            ButtonComponent::Disabled,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::Handler,
+            ButtonComponent::Handler,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::State,
+            ButtonComponent::State,
+        ))
+        .or(parsers::unknown_tag(keywords, TagBlock::Button).map(ButtonComponent::Unknown))
+        .map_with_span(Spanned::new)
+        .padded();
+
+    // Return button parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Button), just("}"))
+        .collect()
+        .map(ActionRowComponent::Button)
+}
+
+/// Parse a select menu into a VeaScript action row component.
+pub fn parse_select(
+    keywords: &Keywords,
+) -> impl Parser<char, ActionRowComponent, Error = ParseError> {
+    // Create select menu component parser
+    let component_parser = parse_name_value(keywords, Keyword::Type, SelectComponent::Kind)
+        .or(parse_string_value(
+            keywords,
+            Keyword::CustomId,
+            SelectComponent::CustomId,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::Placeholder,
+            SelectComponent::Placeholder,
+        ))
+        .or(parse_number_value(
+            keywords,
+            Keyword::MinValues,
+            SelectComponent::MinValues,
+        ))
+        .or(parse_number_value(
+            keywords,
+            Keyword::MaxValues,
+            SelectComponent::MaxValues,
+        ))
+        .or(parse_bool_value(
+            keywords,
+            Keyword::Disabled,
+            SelectComponent::Disabled,
+        ))
+        .or(parse_select_option(keywords))
+        .or(parse_string_value(
+            keywords,
+            Keyword::Handler,
+            SelectComponent::Handler,
+        ))
+        .or(parse_string_value(
+            keywords,
+            Keyword::State,
+            SelectComponent::State,
+        ))
+        .or(parsers::unknown_tag(keywords, TagBlock::Select).map(SelectComponent::Unknown))
+        .map_with_span(Spanned::new)
+        .padded();
+
+    // Return select menu parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Select), just("}"))
+        .collect()
+        .map(ActionRowComponent::Select)
+}
+
+/// Parse a select menu option into a VeaScript select menu component.
+pub fn parse_select_option(
+    keywords: &Keywords,
+) -> impl Parser<char, SelectComponent, Error = ParseError> {
+    // Create option component parser
+    let component_parser =
+        parse_string_value(keywords, Keyword::Label, SelectOptionComponent::Label)
+            .or(parse_string_value(
+                keywords,
+                Keyword::Value,
+                SelectOptionComponent::Value,
+            ))
+            .or(parse_string_value(
+                keywords,
+                Keyword::Description,
+                SelectOptionComponent::Description,
+            ))
+            .or(parse_string_value(
+                keywords,
+                Keyword::Emoji,
+                SelectOptionComponent::Emoji,
+            ))
+            .or(parse_bool_value(
+                keywords,
+                Keyword::Default,
+                SelectOptionComponent::Default,
+            ))
+            .or(parsers::unknown_tag(keywords, TagBlock::SelectOption)
+                .map(SelectOptionComponent::Unknown))
+            .map_with_span(Spanned::new)
+            .padded();
+
+    // Return option parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Option), just("}"))
+        .collect()
+        .map(SelectComponent::Option)
+}
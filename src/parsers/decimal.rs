@@ -1,11 +1,35 @@
+use crate::parsers::ParseError;
 use chumsky::prelude::*;
 
 /// Parse a base 10 32-bit integer.
-pub fn int32() -> impl Parser<char, i32, Error = Simple<char>> {
-    text::int(10).map(|s: String| s.parse().unwrap()).padded()
+pub fn int32() -> impl Parser<char, i32, Error = ParseError> {
+    text::int(10)
+        .try_map(|s: String, span| {
+            s.parse()
+                .map_err(|_| Simple::custom(span, format!("The number {} is too large.", s)))
+        })
+        .padded()
 }
 
 /// Parse a base 10 64-bit signed integer.
-pub fn int64() -> impl Parser<char, i64, Error = Simple<char>> {
-    text::int(10).map(|s: String| s.parse().unwrap()).padded()
+pub fn int64() -> impl Parser<char, i64, Error = ParseError> {
+    text::int(10)
+        .try_map(|s: String, span| {
+            s.parse()
+                .map_err(|_| Simple::custom(span, format!("The number {} is too large.", s)))
+        })
+        .padded()
+}
+
+/// Parse a base 10 number, which may have a fractional part.
+pub fn float() -> impl Parser<char, f64, Error = ParseError> {
+    text::int(10)
+        .then(just('.').ignore_then(text::digits(10)).or_not())
+        .map(
+            |(whole, fraction): (String, Option<String>)| match fraction {
+                Some(fraction) => format!("{}.{}", whole, fraction).parse().unwrap(),
+                None => whole.parse().unwrap(),
+            },
+        )
+        .padded()
 }
@@ -1,279 +1,333 @@
 use crate::enums::{
-    EmbedAuthorComponent, EmbedComponent, EmbedFieldComponent, EmbedFooterComponent, Expr,
+    EmbedAuthorComponent, EmbedComponent, EmbedFieldComponent, EmbedFooterComponent, Expr, Keyword,
+    TagBlock,
 };
-use crate::helpers::hexadecimal;
 use crate::parsers;
-use crate::structs::StoredEmbed;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
 use chumsky::prelude::*;
-use serenity::all::EmbedField;
 
 /// Parse an embed into a VeaScript embed.
-pub fn parse_embed() -> impl Parser<char, Expr, Error = Simple<char>> {
+pub fn parse_embed(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
     // Return embed parser
-    parse_embed_raw().map(Expr::Embed)
+    parse_embed_raw(keywords).map(Expr::Embed)
 }
 
 /// Parse an embed into a vector of embed components.
-pub fn parse_embed_raw() -> impl Parser<char, Vec<EmbedComponent>, Error = Simple<char>> {
+pub fn parse_embed_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<EmbedComponent>>, Error = ParseError> {
     // Create embed parser
-    let embed_parser = parse_embed_component()
+    let embed_parser = parse_embed_component(keywords)
         .repeated()
-        .delimited_by(just("#embed {"), just("}"))
+        .delimited_by(parsers::block_start(keywords, Keyword::Embed), just("}"))
         .collect();
 
     // Return embed parser
-    embed_parser.padded()
+    embed_parser
 }
 
 /// Parse an embed component.
-pub fn parse_embed_component() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+pub fn parse_embed_component(
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<EmbedComponent>, Error = ParseError> {
     // Create embed component parser
-    let component_parser = parse_embed_title()
-        .or(parse_embed_description())
-        .or(parse_embed_colour())
-        .or(parse_embed_image())
-        .or(parse_embed_thumbnail())
-        .or(parse_embed_url())
-        .or(parse_embed_author())
-        .or(parse_embed_footer())
-        .or(parse_embed_timestamp())
-        .or(parse_embed_fields());
+    let component_parser = parse_embed_title(keywords)
+        .or(parse_embed_description(keywords))
+        .or(parse_embed_colour(keywords))
+        .or(parse_embed_image(keywords))
+        .or(parse_embed_thumbnail(keywords))
+        .or(parse_embed_url(keywords))
+        .or(parse_embed_author(keywords))
+        .or(parse_embed_footer(keywords))
+        .or(parse_embed_timestamp(keywords))
+        .or(parse_embed_fields(keywords))
+        .or(parsers::unknown_tag(keywords, TagBlock::Embed).map(EmbedComponent::Unknown));
 
     // Return embed component parser
-    component_parser.padded()
+    component_parser.map_with_span(Spanned::new).padded()
 }
 
 /// Parse a string field for an embed.
 pub fn parse_string_field(
-    tag: &'static str,
+    keywords: &Keywords,
+    keyword: Keyword,
     component: impl Fn(String) -> EmbedComponent,
-) -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     // Return title parser
     parsers::string()
-        .delimited_by(just(tag), just(','))
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
         .map(component)
 }
 
 /// Parse an embed title.
-pub fn parse_embed_title() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    parse_string_field("#title:", EmbedComponent::Title)
+pub fn parse_embed_title(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
+    parse_string_field(keywords, Keyword::Title, EmbedComponent::Title)
 }
 
 /// Parse an embed description.
-pub fn parse_embed_description() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    parse_string_field("#description:", EmbedComponent::Description)
+pub fn parse_embed_description(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
+    parse_string_field(keywords, Keyword::Description, EmbedComponent::Description)
 }
 
 /// Parse an embed image.
-pub fn parse_embed_image() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    parse_string_field("#image:", EmbedComponent::Image)
+pub fn parse_embed_image(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
+    parse_string_field(keywords, Keyword::Image, EmbedComponent::Image)
 }
 
 /// Parse an embed thumbnail.
-pub fn parse_embed_thumbnail() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    parse_string_field("#thumbnail:", EmbedComponent::Thumbnail)
+pub fn parse_embed_thumbnail(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
+    parse_string_field(keywords, Keyword::Thumbnail, EmbedComponent::Thumbnail)
 }
 
 /// Parse an embed url.
-pub fn parse_embed_url() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    parse_string_field("#url:", EmbedComponent::Url)
+pub fn parse_embed_url(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
+    parse_string_field(keywords, Keyword::Url, EmbedComponent::Url)
 }
 
 /// Parse an embed timestamp.
-pub fn parse_embed_timestamp() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+pub fn parse_embed_timestamp(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     parsers::int64()
-        .delimited_by(just("#timestamp:"), just(','))
+        .delimited_by(
+            parsers::field_start(keywords, Keyword::Timestamp),
+            just(','),
+        )
         .map(EmbedComponent::Timestamp)
 }
 
 /// Parse an embed colour.
-pub fn parse_embed_colour() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
-    // Create colour parser
-    let colour = parsers::hex().or(parsers::int32()).padded();
-
+pub fn parse_embed_colour(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     // Return colour field parser
-    colour
-        .delimited_by(just("#colour:"), just(','))
-        .padded()
+    parsers::colour()
+        .delimited_by(parsers::field_start(keywords, Keyword::Colour), just(','))
         .map(EmbedComponent::Colour)
 }
 
 /// Parse an embed author into a VeaScript embed component.
-pub fn parse_embed_author() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+pub fn parse_embed_author(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     // Return embed parser
-    parse_embed_author_raw().map(EmbedComponent::Author)
+    parse_embed_author_raw(keywords).map(EmbedComponent::Author)
 }
 
 /// Parse an embed author into a vector of embed components.
-pub fn parse_embed_author_raw() -> impl Parser<char, Vec<EmbedAuthorComponent>, Error = Simple<char>>
-{
+pub fn parse_embed_author_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<EmbedAuthorComponent>>, Error = ParseError> {
     // Create author parser
-    let author_parser = parse_embed_author_component()
+    let author_parser = parse_embed_author_component(keywords)
         .repeated()
-        .delimited_by(just("#author {"), just("}"))
+        .delimited_by(parsers::block_start(keywords, Keyword::Author), just("}"))
         .collect();
 
     // Return author parser
-    author_parser.padded()
+    author_parser
 }
 
 /// Parse an embed author component.
 pub fn parse_embed_author_component(
-) -> impl Parser<char, EmbedAuthorComponent, Error = Simple<char>> {
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<EmbedAuthorComponent>, Error = ParseError> {
     // Create author component parser
-    let component_parser = parse_embed_author_name()
-        .or(parse_embed_author_url())
-        .or(parse_embed_author_icon_url());
+    let component_parser = parse_embed_author_name(keywords)
+        .or(parse_embed_author_url(keywords))
+        .or(parse_embed_author_icon_url(keywords))
+        .or(parsers::unknown_tag(keywords, TagBlock::Author).map(EmbedAuthorComponent::Unknown));
 
     // Return author component parser
-    component_parser.padded()
+    component_parser.map_with_span(Spanned::new).padded()
 }
 
 /// Parse a string field for an embed author.
 pub fn parse_author_string_field(
-    tag: &'static str,
+    keywords: &Keywords,
+    keyword: Keyword,
     component: impl Fn(String) -> EmbedAuthorComponent,
-) -> impl Parser<char, EmbedAuthorComponent, Error = Simple<char>> {
+) -> impl Parser<char, EmbedAuthorComponent, Error = ParseError> {
     // Return title parser
     parsers::string()
-        .delimited_by(just(tag), just(','))
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
         .map(component)
 }
 
 /// Parse an embed author's name.
-pub fn parse_embed_author_name() -> impl Parser<char, EmbedAuthorComponent, Error = Simple<char>> {
-    parse_author_string_field("#name:", EmbedAuthorComponent::Name)
+pub fn parse_embed_author_name(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedAuthorComponent, Error = ParseError> {
+    parse_author_string_field(keywords, Keyword::Name, EmbedAuthorComponent::Name)
 }
 
 /// Parse an embed author's url.
-pub fn parse_embed_author_url() -> impl Parser<char, EmbedAuthorComponent, Error = Simple<char>> {
-    parse_author_string_field("#url:", EmbedAuthorComponent::Url)
+pub fn parse_embed_author_url(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedAuthorComponent, Error = ParseError> {
+    parse_author_string_field(keywords, Keyword::Url, EmbedAuthorComponent::Url)
 }
 
 /// Parse an embed author's icon url.
-pub fn parse_embed_author_icon_url() -> impl Parser<char, EmbedAuthorComponent, Error = Simple<char>>
-{
-    parse_author_string_field("#icon_url:", EmbedAuthorComponent::IconUrl)
+pub fn parse_embed_author_icon_url(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedAuthorComponent, Error = ParseError> {
+    parse_author_string_field(keywords, Keyword::IconUrl, EmbedAuthorComponent::IconUrl)
 }
 
 /// Parse an embed footer into a VeaScript embed component.
-pub fn parse_embed_footer() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+pub fn parse_embed_footer(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     // Return embed parser
-    parse_embed_footer_raw().map(EmbedComponent::Footer)
+    parse_embed_footer_raw(keywords).map(EmbedComponent::Footer)
 }
 
 /// Parse an embed footer into a vector of embed components.
-pub fn parse_embed_footer_raw() -> impl Parser<char, Vec<EmbedFooterComponent>, Error = Simple<char>>
-{
+pub fn parse_embed_footer_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<EmbedFooterComponent>>, Error = ParseError> {
     // Create footer parser
-    let footer_parser = parse_embed_footer_component()
+    let footer_parser = parse_embed_footer_component(keywords)
         .repeated()
-        .delimited_by(just("#footer {"), just("}"))
+        .delimited_by(parsers::block_start(keywords, Keyword::Footer), just("}"))
         .collect();
 
     // Return footer parser
-    footer_parser.padded()
+    footer_parser
 }
 
 /// Parse an embed footer component.
 pub fn parse_embed_footer_component(
-) -> impl Parser<char, EmbedFooterComponent, Error = Simple<char>> {
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<EmbedFooterComponent>, Error = ParseError> {
     // Create footer component parser
-    let component_parser = parse_embed_footer_text().or(parse_embed_footer_icon_url());
+    let component_parser = parse_embed_footer_text(keywords)
+        .or(parse_embed_footer_icon_url(keywords))
+        .or(parsers::unknown_tag(keywords, TagBlock::Footer).map(EmbedFooterComponent::Unknown));
 
     // Return footer component parser
-    component_parser.padded()
+    component_parser.map_with_span(Spanned::new).padded()
 }
 
 /// Parse a string field for an embed footer.
 pub fn parse_footer_string_field(
-    tag: &'static str,
+    keywords: &Keywords,
+    keyword: Keyword,
     component: impl Fn(String) -> EmbedFooterComponent,
-) -> impl Parser<char, EmbedFooterComponent, Error = Simple<char>> {
+) -> impl Parser<char, EmbedFooterComponent, Error = ParseError> {
     // Return title parser
     parsers::string()
-        .delimited_by(just(tag), just(','))
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
         .map(component)
 }
 
 /// Parse an embed footer's text.
-pub fn parse_embed_footer_text() -> impl Parser<char, EmbedFooterComponent, Error = Simple<char>> {
-    parse_footer_string_field("#text:", EmbedFooterComponent::Text)
+pub fn parse_embed_footer_text(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedFooterComponent, Error = ParseError> {
+    parse_footer_string_field(keywords, Keyword::Text, EmbedFooterComponent::Text)
 }
 
 /// Parse an embed footer's icon url.
-pub fn parse_embed_footer_icon_url() -> impl Parser<char, EmbedFooterComponent, Error = Simple<char>>
-{
-    parse_footer_string_field("#icon_url:", EmbedFooterComponent::IconUrl)
+pub fn parse_embed_footer_icon_url(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedFooterComponent, Error = ParseError> {
+    parse_footer_string_field(keywords, Keyword::IconUrl, EmbedFooterComponent::IconUrl)
 }
 
 /// Parse an embed's fields into a VeaScript embed component.
-pub fn parse_embed_fields() -> impl Parser<char, EmbedComponent, Error = Simple<char>> {
+pub fn parse_embed_fields(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedComponent, Error = ParseError> {
     // Return embed parser
-    parse_embed_fields_raw().map(EmbedComponent::Fields)
+    parse_embed_fields_raw(keywords).map(EmbedComponent::Fields)
 }
 
 /// Parse an embed's fields into a vector of vectors of embed components.
 pub fn parse_embed_fields_raw(
-) -> impl Parser<char, Vec<Vec<EmbedFieldComponent>>, Error = Simple<char>> {
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<Vec<Spanned<EmbedFieldComponent>>>>, Error = ParseError> {
     // Create field parser
-    let field_parser = parse_embed_field()
+    let field_parser = parse_embed_field(keywords)
         .repeated()
-        .delimited_by(just("#fields {"), just("}"))
+        .delimited_by(parsers::block_start(keywords, Keyword::Fields), just("}"))
         .collect();
 
     // Return footer parser
-    field_parser.padded()
+    field_parser
 }
 
 /// Parse an embed field into a vector of VeaScript embed field components.
-pub fn parse_embed_field() -> impl Parser<char, Vec<EmbedFieldComponent>, Error = Simple<char>> {
+pub fn parse_embed_field(
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<Vec<Spanned<EmbedFieldComponent>>>, Error = ParseError> {
     // Create field parser
-    let footer_parser = parse_embed_field_component()
+    let footer_parser = parse_embed_field_component(keywords)
         .repeated()
-        .delimited_by(just("#field {"), just("}"))
+        .delimited_by(parsers::block_start(keywords, Keyword::Field), just("}"))
         .collect();
 
     // Return footer parser
-    footer_parser.padded()
+    footer_parser.map_with_span(Spanned::new).padded()
 }
 
 /// Parse an embed field component.
-pub fn parse_embed_field_component() -> impl Parser<char, EmbedFieldComponent, Error = Simple<char>>
-{
+pub fn parse_embed_field_component(
+    keywords: &Keywords,
+) -> impl Parser<char, Spanned<EmbedFieldComponent>, Error = ParseError> {
     // Create field component parser
-    let component_parser = parse_embed_field_name()
-        .or(parse_embed_field_value())
-        .or(parse_embed_field_inline());
+    let component_parser = parse_embed_field_name(keywords)
+        .or(parse_embed_field_value(keywords))
+        .or(parse_embed_field_inline(keywords))
+        .or(parsers::unknown_tag(keywords, TagBlock::Field).map(EmbedFieldComponent::Unknown));
 
     // Return field component parser
-    component_parser.padded()
+    component_parser.map_with_span(Spanned::new).padded()
 }
 
 /// Parse a string field for an embed field.
 pub fn parse_field_string_field(
-    tag: &'static str,
+    keywords: &Keywords,
+    keyword: Keyword,
     component: impl Fn(String) -> EmbedFieldComponent,
-) -> impl Parser<char, EmbedFieldComponent, Error = Simple<char>> {
+) -> impl Parser<char, EmbedFieldComponent, Error = ParseError> {
     // Return title parser
     parsers::string()
-        .delimited_by(just(tag), just(','))
+        .delimited_by(parsers::field_start(keywords, keyword), just(','))
         .map(component)
 }
 
 /// Parse an embed field's name.
-pub fn parse_embed_field_name() -> impl Parser<char, EmbedFieldComponent, Error = Simple<char>> {
-    parse_field_string_field("#name:", EmbedFieldComponent::Name)
+pub fn parse_embed_field_name(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedFieldComponent, Error = ParseError> {
+    parse_field_string_field(keywords, Keyword::Name, EmbedFieldComponent::Name)
 }
 
 /// Parse an embed field's value.
-pub fn parse_embed_field_value() -> impl Parser<char, EmbedFieldComponent, Error = Simple<char>> {
-    parse_field_string_field("#value:", EmbedFieldComponent::Value)
+pub fn parse_embed_field_value(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedFieldComponent, Error = ParseError> {
+    parse_field_string_field(keywords, Keyword::Value, EmbedFieldComponent::Value)
 }
 
 /// Parse an embed field's inline setting.
-pub fn parse_embed_field_inline() -> impl Parser<char, EmbedFieldComponent, Error = Simple<char>> {
+pub fn parse_embed_field_inline(
+    keywords: &Keywords,
+) -> impl Parser<char, EmbedFieldComponent, Error = ParseError> {
     parsers::boolean()
-        .delimited_by(just("#inline:"), just(','))
+        .delimited_by(parsers::field_start(keywords, Keyword::Inline), just(','))
         .map(EmbedFieldComponent::Inline)
 }
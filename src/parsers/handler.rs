@@ -0,0 +1,20 @@
+use crate::enums::{Expr, Keyword};
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::Keywords;
+use chumsky::prelude::*;
+
+/// Parse a component handler block, such as `#on_click "next" { ... }`, into a VeaScript handler.
+pub fn parse_handler(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Create handler start parser, which reads the handler's name
+    let start = parsers::tag(keywords, Keyword::OnClick)
+        .ignore_then(parsers::string().padded())
+        .then_ignore(just('{'));
+
+    // Return handler parser
+    start
+        .then(parsers::message_body(keywords))
+        .then_ignore(just('}'))
+        .map(|(name, expressions)| Expr::Handler(name, expressions))
+        .labelled("handler")
+}
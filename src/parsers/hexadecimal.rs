@@ -1,9 +1,14 @@
+use crate::parsers::ParseError;
 use chumsky::prelude::*;
 
 /// Parse a hexadecimal value starting with # or 0x.
-pub fn hexadecimal() -> impl Parser<char, i32, Error = Simple<char>> {
+pub fn hexadecimal() -> impl Parser<char, i64, Error = ParseError> {
     just("0x")
         .or(just("#"))
         .ignore_then(text::digits(16))
-        .map(|s: String| i32::from_str_radix(&s, 16).unwrap())
+        .try_map(|s: String, span| {
+            i64::from_str_radix(&s, 16).map_err(|_| {
+                Simple::custom(span, format!("The hexadecimal value #{} is too large.", s))
+            })
+        })
 }
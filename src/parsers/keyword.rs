@@ -0,0 +1,36 @@
+use crate::enums::Keyword;
+use crate::parsers::ParseError;
+use crate::structs::Keywords;
+use chumsky::prelude::*;
+use std::cmp::Reverse;
+
+/// Parse a tag written with any accepted spelling of a keyword, such as `#colour` or `#color`.
+pub fn parse_tag(
+    keywords: &Keywords,
+    keyword: Keyword,
+) -> impl Parser<char, String, Error = ParseError> + Clone {
+    // Get spellings, longest first so that a shorter spelling can't shadow a longer one
+    let mut spellings = keywords.spellings(keyword).to_vec();
+    spellings.sort_by_key(|spelling| Reverse(spelling.len()));
+
+    // Return tag parser
+    just('#')
+        .ignore_then(choice(spellings.into_iter().map(just).collect::<Vec<_>>()))
+        .labelled(keyword.canonical())
+}
+
+/// Parse the opening of a block, such as `#embed {`.
+pub fn parse_block_start(
+    keywords: &Keywords,
+    keyword: Keyword,
+) -> impl Parser<char, String, Error = ParseError> + Clone {
+    parse_tag(keywords, keyword).then_ignore(just(" {"))
+}
+
+/// Parse the start of a field, such as `#title:`.
+pub fn parse_field_start(
+    keywords: &Keywords,
+    keyword: Keyword,
+) -> impl Parser<char, String, Error = ParseError> + Clone {
+    parse_tag(keywords, keyword).then_ignore(just(':'))
+}
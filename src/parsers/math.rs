@@ -1,36 +1,46 @@
-use crate::enums::{Expr, MathExpr};
+use crate::enums::{Expr, Keyword, MathExpr};
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Span, Spanned};
 use chumsky::prelude::*;
 
 /// Parse a string of math characters into a VeaScript math expression.
-pub fn parse_math() -> impl Parser<char, Expr, Error = Simple<char>> {
-    let math_parser = parse_math_raw()
-        .delimited_by(just("#math {"), just("}"))
+pub fn parse_math(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    parse_math_raw()
+        .delimited_by(parsers::block_start(keywords, Keyword::Math), just("}"))
         .map(Expr::Math)
-        .labelled("math expression");
-
-    math_parser.padded()
+        .labelled("math expression")
 }
 
 /// Parse a string of characters into a math expression.
-pub fn parse_math_raw() -> impl Parser<char, MathExpr, Error = Simple<char>> {
-    // Create recursive parser
-    recursive(|expr| {
+pub fn parse_math_raw() -> impl Parser<char, Spanned<MathExpr>, Error = ParseError> {
+    // Create nested parser
+    parsers::nested(parsers::MAX_NESTING, |expr| {
         // Create parser to read in an unsigned integer
         let int = text::int(10)
-            .map(|s: String| MathExpr::Num(s.parse().unwrap()))
+            .map_with_span(|s: String, span| Spanned::new(MathExpr::Num(s.parse().unwrap()), span))
             .padded();
 
         // Create atom parser, which is either an unsigned integer or paratheses containing an expression
         let atom = int.or(expr.delimited_by(just('('), just(')'))).padded();
 
-        // Create operator parser
-        let op = |c| just(c).padded();
+        // Create operator parser, which outputs the operator's span
+        let op = |c| just(c).map_with_span(|_, span: Span| span).padded();
 
         // Create unary parser, which is an atom with an optional '-' in front of it.
         let unary = op('-')
             .repeated()
             .then(atom)
-            .foldr(|_op, rhs| MathExpr::Neg(Box::new(rhs)));
+            .foldr(|op_span, rhs: Spanned<MathExpr>| {
+                let span = op_span.union(rhs.span);
+                Spanned::new(MathExpr::Neg(Box::new(rhs)), span)
+            });
+
+        // Create binary operator combiner, which spans both sides of the operator
+        let binary = |lhs: Spanned<MathExpr>, (op, rhs): (fn(_, _) -> _, Spanned<MathExpr>)| {
+            let span = lhs.span.union(rhs.span);
+            Spanned::new(op(Box::new(lhs), Box::new(rhs)), span)
+        };
 
         // Create product parser, which parses multiplication and division.
         let product = unary
@@ -42,7 +52,7 @@ pub fn parse_math_raw() -> impl Parser<char, MathExpr, Error = Simple<char>> {
                     .then(unary)
                     .repeated(),
             )
-            .foldl(|lhs, (op, rhs)| op(Box::new(lhs), Box::new(rhs)));
+            .foldl(binary);
 
         // Create sum parser, which parses addition and subtraction after multiplication and division.
         let sum = product
@@ -54,7 +64,7 @@ pub fn parse_math_raw() -> impl Parser<char, MathExpr, Error = Simple<char>> {
                     .then(product)
                     .repeated(),
             )
-            .foldl(|lhs, (op, rhs)| op(Box::new(lhs), Box::new(rhs)));
+            .foldl(binary);
 
         // Return sum parser.
         sum
@@ -0,0 +1,34 @@
+use crate::enums::{Expr, Keyword};
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse a message block, such as `#message { ... }`, into a VeaScript message.
+pub fn parse_message(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Return message parser
+    parse_message_body(keywords)
+        .delimited_by(parsers::block_start(keywords, Keyword::Message), just('}'))
+        .map(Expr::Message)
+        .labelled("message")
+}
+
+/// Parse the expressions inside a block that describes a single message.
+pub fn parse_message_body(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<Expr>>, Error = ParseError> {
+    parsers::random(keywords)
+        .or(parsers::math(keywords))
+        .or(parsers::text(keywords))
+        .or(parsers::embed(keywords))
+        .or(parsers::attachment(keywords))
+        .or(parsers::action_row(keywords))
+        .or(parsers::poll(keywords))
+        .or(parsers::settings(keywords))
+        .or(parsers::allowed_mentions(keywords))
+        .or(parsers::webhook(keywords))
+        .map_with_span(Spanned::new)
+        .padded()
+        .repeated()
+        .padded()
+}
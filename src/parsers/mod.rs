@@ -1,19 +1,53 @@
+use crate::structs::Span;
+use chumsky::prelude::Simple;
+
 // Import parser modules
+pub mod attachment;
 pub mod boolean;
+pub mod colour;
+pub mod component;
 pub mod decimal;
 pub mod embed;
+pub mod escape;
+pub mod handler;
 pub mod hexadecimal;
+pub mod keyword;
 pub mod math;
+pub mod message;
+pub mod nested;
+pub mod poll;
 pub mod random;
+pub mod settings;
+pub mod stream;
 pub mod string;
 pub mod text;
+pub mod unknown;
+pub mod webhook;
 
 // Re-export parsers
+pub use attachment::parse_attachment as attachment;
 pub use boolean::parse_bool as boolean;
-pub use decimal::{int32, int64};
+pub use colour::parse_colour as colour;
+pub use component::parse_action_row as action_row;
+pub use decimal::{float, int32, int64};
 pub use embed::parse_embed as embed;
+pub use escape::parse_escape as escape;
+pub use handler::parse_handler as handler;
 pub use hexadecimal::hexadecimal as hex;
+pub use keyword::{
+    parse_block_start as block_start, parse_field_start as field_start, parse_tag as tag,
+};
 pub use math::parse_math as math;
+pub use message::{parse_message as message, parse_message_body as message_body};
+pub use nested::{parse_nested as nested, SyncParser, MAX_NESTING};
+pub use poll::parse_poll as poll;
 pub use random::parse_random as random;
+pub use settings::{parse_allowed_mentions as allowed_mentions, parse_settings as settings};
+pub use stream::source_stream as stream;
 pub use string::parse_string as string;
 pub use text::parse_text as text;
+pub use unknown::parse_unknown_tag as unknown_tag;
+pub use webhook::parse_webhook as webhook;
+
+/// The error type produced by VeaScript parsers.
+pub type ParseError = Simple<char, Span>;
@@ -0,0 +1,35 @@
+use crate::parsers::ParseError;
+use chumsky::prelude::*;
+use std::sync::Arc;
+
+/// A type-erased parser that can be shared between threads.
+pub type SyncParser<O> = Arc<dyn Parser<char, O, Error = ParseError> + Send + Sync>;
+
+/// The maximum depth that nested parsers (such as parentheses in math expressions) can reach.
+pub const MAX_NESTING: usize = 64;
+
+/// Create a parser that may contain itself, up to a given nesting depth.
+///
+/// This mirrors chumsky's `recursive`, but builds each level of nesting up front so that the
+/// resulting parser is `Send + Sync` and can be reused across threads.
+pub fn parse_nested<O, P>(depth: usize, parser: impl Fn(SyncParser<O>) -> P) -> SyncParser<O>
+where
+    O: 'static,
+    P: Parser<char, O, Error = ParseError> + Send + Sync + 'static,
+{
+    // Create innermost level, which always fails
+    let mut level: SyncParser<O> = Arc::new(any().try_map(move |_, span| {
+        Err(Simple::custom(
+            span,
+            format!("Nesting is too deep (the maximum depth is {}).", depth),
+        ))
+    }));
+
+    // Wrap each level of nesting around the last
+    for _ in 0..=depth {
+        level = Arc::new(parser(level));
+    }
+
+    // Return outermost level
+    level
+}
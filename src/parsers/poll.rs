@@ -0,0 +1,68 @@
+use crate::enums::{Expr, Keyword, PollAnswerComponent, PollComponent, TagBlock};
+use crate::parsers;
+use crate::parsers::component::{parse_bool_value, parse_number_value, parse_string_value};
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse a poll into a VeaScript poll.
+pub fn parse_poll(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Return poll parser
+    parse_poll_raw(keywords).map(Expr::Poll)
+}
+
+/// Parse a poll into a vector of poll components.
+pub fn parse_poll_raw(
+    keywords: &Keywords,
+) -> impl Parser<char, Vec<Spanned<PollComponent>>, Error = ParseError> {
+    // Create poll component parser
+    let component_parser = parse_string_value(keywords, Keyword::Question, PollComponent::Question)
+        .or(parse_poll_answer(keywords))
+        .or(parse_number_value(
+            keywords,
+            Keyword::Duration,
+            PollComponent::Duration,
+        ))
+        .or(parse_bool_value(
+            keywords,
+            Keyword::MultiSelect,
+            PollComponent::MultiSelect,
+        ))
+        .or(parse_bool_value(
+            keywords,
+            Keyword::Shuffle,
+            PollComponent::Shuffle,
+        ))
+        .or(parsers::unknown_tag(keywords, TagBlock::Poll).map(PollComponent::Unknown))
+        .map_with_span(Spanned::new)
+        .padded();
+
+    // Return poll parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Poll), just("}"))
+        .collect()
+}
+
+/// Parse a poll answer into a poll component.
+pub fn parse_poll_answer(
+    keywords: &Keywords,
+) -> impl Parser<char, PollComponent, Error = ParseError> {
+    // Create answer component parser
+    let component_parser = parse_string_value(keywords, Keyword::Label, PollAnswerComponent::Label)
+        .or(parse_string_value(
+            keywords,
+            Keyword::Emoji,
+            PollAnswerComponent::Emoji,
+        ))
+        .or(parsers::unknown_tag(keywords, TagBlock::PollAnswer).map(PollAnswerComponent::Unknown))
+        .map_with_span(Spanned::new)
+        .padded();
+
+    // Return answer parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Answer), just("}"))
+        .collect()
+        .map(PollComponent::Answer)
+}
@@ -1,19 +1,19 @@
-use crate::enums::Expr;
+use crate::enums::{Expr, Keyword};
 use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::Keywords;
 use chumsky::prelude::*;
 
 /// Parse a string of characters into a random expression.
-pub fn parse_random() -> impl Parser<char, Expr, Error = Simple<char>> {
+pub fn parse_random(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
     // Create parser to read a string
 
-    // Create parser to read random list
-    let random = parsers::string()
+    // Return random list parser
+    parsers::string()
         .chain(just(',').ignore_then(parsers::string()).repeated())
         .or_not()
         .flatten()
-        .delimited_by(just("#random {"), just('}'))
+        .delimited_by(parsers::block_start(keywords, Keyword::Random), just('}'))
         .map(Expr::Random)
-        .labelled("random array");
-
-    random.padded()
+        .labelled("random array")
 }
@@ -0,0 +1,77 @@
+use crate::enums::{AllowedMentionsComponent, Expr, Keyword, SettingsComponent, TagBlock};
+use crate::parsers;
+use crate::parsers::component::parse_bool_value;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse a settings block into VeaScript message settings.
+pub fn parse_settings(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Create settings component parser
+    let component_parser = parse_bool_value(
+        keywords,
+        Keyword::SuppressEmbeds,
+        SettingsComponent::SuppressEmbeds,
+    )
+    .or(parse_bool_value(
+        keywords,
+        Keyword::Silent,
+        SettingsComponent::Silent,
+    ))
+    .or(parse_bool_value(
+        keywords,
+        Keyword::Ephemeral,
+        SettingsComponent::Ephemeral,
+    ))
+    .or(parse_bool_value(
+        keywords,
+        Keyword::Tts,
+        SettingsComponent::Tts,
+    ))
+    .or(parsers::unknown_tag(keywords, TagBlock::Settings).map(SettingsComponent::Unknown))
+    .map_with_span(Spanned::new)
+    .padded();
+
+    // Return settings parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Settings), just("}"))
+        .collect()
+        .map(Expr::Settings)
+}
+
+/// Parse an allowed mentions block into VeaScript allowed mentions.
+pub fn parse_allowed_mentions(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Create allowed mentions component parser
+    let component_parser =
+        parse_bool_value(keywords, Keyword::Users, AllowedMentionsComponent::Users)
+            .or(parse_bool_value(
+                keywords,
+                Keyword::Roles,
+                AllowedMentionsComponent::Roles,
+            ))
+            .or(parse_bool_value(
+                keywords,
+                Keyword::Everyone,
+                AllowedMentionsComponent::Everyone,
+            ))
+            .or(parse_bool_value(
+                keywords,
+                Keyword::RepliedUser,
+                AllowedMentionsComponent::RepliedUser,
+            ))
+            .or(parsers::unknown_tag(keywords, TagBlock::AllowedMentions)
+                .map(AllowedMentionsComponent::Unknown))
+            .map_with_span(Spanned::new)
+            .padded();
+
+    // Return allowed mentions parser
+    component_parser
+        .repeated()
+        .delimited_by(
+            parsers::block_start(keywords, Keyword::AllowedMentions),
+            just("}"),
+        )
+        .collect()
+        .map(Expr::AllowedMentions)
+}
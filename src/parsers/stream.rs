@@ -0,0 +1,20 @@
+use crate::structs::{FileId, Span};
+use chumsky::Stream;
+
+/// Create a stream of characters from a VeaScript source file, with each character spanned.
+pub fn source_stream(
+    file: FileId,
+    src: &str,
+) -> Stream<'_, char, Span, impl Iterator<Item = (char, Span)> + '_> {
+    // Get the end of input span
+    let length = src.chars().count();
+    let eoi = Span::new(file, length..length);
+
+    // Return stream
+    Stream::from_iter(
+        eoi,
+        src.chars()
+            .enumerate()
+            .map(move |(i, c)| (c, Span::new(file, i..i + 1))),
+    )
+}
@@ -1,9 +1,15 @@
+use crate::parsers;
+use crate::parsers::ParseError;
 use chumsky::prelude::*;
 
-/// Parse a string.
-pub fn parse_string() -> impl Parser<char, String, Error = Simple<char>> {
+/// Parse a string, which may contain escape sequences.
+pub fn parse_string() -> impl Parser<char, String, Error = ParseError> {
     just("\"")
-        .ignore_then(filter(|c| *c != '\\' && *c != '\"').repeated())
+        .ignore_then(
+            filter(|c| *c != '\\' && *c != '\"')
+                .or(parsers::escape())
+                .repeated(),
+        )
         .then_ignore(just("\""))
         .padded()
         .collect::<String>()
@@ -0,0 +1,48 @@
+use crate::enums::TagBlock;
+use crate::helpers::suggestions;
+use crate::parsers;
+use crate::parsers::ParseError;
+use crate::structs::Keywords;
+use chumsky::prelude::*;
+
+/// The maximum depth of nested blocks that can be skipped inside an unknown tag.
+const MAX_BLOCK_NESTING: usize = 8;
+
+/// Parse any tag generically, returning an error message describing it.
+///
+/// This is used as a fallback after all known tags for a block, so that unknown tags can be
+/// reported with a helpful error instead of failing the parse of the whole block.
+pub fn parse_unknown_tag(
+    keywords: &Keywords,
+    block: TagBlock,
+) -> impl Parser<char, String, Error = ParseError> {
+    // Clone keywords for suggestions
+    let keywords = keywords.clone();
+
+    // Create block parser, which skips over a block with balanced braces
+    let block_parser = parsers::nested(MAX_BLOCK_NESTING, |inner| {
+        parsers::string()
+            .ignored()
+            .or(inner)
+            .or(filter(|c: &char| !matches!(c, '{' | '}' | '"')).ignored())
+            .repeated()
+            .delimited_by(just('{'), just('}'))
+            .ignored()
+    });
+
+    // Create value parser, which skips over a value up to the next comma
+    let value = parsers::string()
+        .ignored()
+        .or(filter(|c: &char| !matches!(c, ',' | '{' | '}' | '"')).ignored())
+        .repeated()
+        .delimited_by(just(':'), just(','))
+        .ignored();
+
+    // Return unknown tag parser
+    just('#')
+        .ignore_then(text::ident())
+        .then_ignore(value.or(block_parser.padded()))
+        .map(move |tag: String| suggestions::unknown_tag(&tag, block, &keywords))
+        .padded()
+        .labelled("tag")
+}
@@ -0,0 +1,33 @@
+use crate::enums::{Expr, Keyword, TagBlock, WebhookComponent};
+use crate::parsers;
+use crate::parsers::component::parse_string_value;
+use crate::parsers::ParseError;
+use crate::structs::{Keywords, Spanned};
+use chumsky::prelude::*;
+
+/// Parse a webhook block into a VeaScript webhook identity.
+pub fn parse_webhook(keywords: &Keywords) -> impl Parser<char, Expr, Error = ParseError> {
+    // Create webhook component parser
+    let component_parser =
+        parse_string_value(keywords, Keyword::Username, WebhookComponent::Username)
+            .or(parse_string_value(
+                keywords,
+                Keyword::AvatarUrl,
+                WebhookComponent::AvatarUrl,
+            ))
+            .or(parse_string_value(
+                keywords,
+                Keyword::ThreadName,
+                WebhookComponent::ThreadName,
+            ))
+            .or(parsers::unknown_tag(keywords, TagBlock::Webhook).map(WebhookComponent::Unknown))
+            .map_with_span(Spanned::new)
+            .padded();
+
+    // Return webhook parser
+    component_parser
+        .repeated()
+        .delimited_by(parsers::block_start(keywords, Keyword::Webhook), just("}"))
+        .collect()
+        .map(Expr::Webhook)
+}
@@ -0,0 +1,131 @@
+use crate::enums::ColourExpr;
+use crate::helpers::{colour, hexadecimal, suggestions};
+use crate::structs::{BuildError, Spanned};
+use rand::Rng;
+
+/// Evaluate a colour expression into a colour value.
+pub fn eval(expression: &Spanned<ColourExpr>, rng: &mut impl Rng) -> Result<i32, BuildError> {
+    // Get expression span
+    let span = expression.span;
+
+    // Match expression type
+    match &expression.node {
+        ColourExpr::Value(value) => {
+            // Check colour is within the limit
+            if *value > colour::MAX_COLOUR {
+                return Err(BuildError::new(
+                    span,
+                    format!(
+                        "Your colour value (#{}) is greater than the limit of #FFFFFF.",
+                        hexadecimal::hex_to_str(*value)
+                    ),
+                ));
+            }
+
+            // Return colour
+            Ok(*value as i32)
+        }
+        ColourExpr::Named(name) => colour::named(name).ok_or_else(|| {
+            // Create unknown colour error
+            let mut error = format!("Unknown colour name `{}`.", name);
+
+            // Add closest colour name if there is one
+            if let Some(suggestion) = suggestions::closest(name, colour::names()) {
+                error.push_str(&format!(" Did you mean `{}`?", suggestion));
+            }
+
+            // Return error
+            BuildError::new(span, error)
+        }),
+        ColourExpr::Rgb(red, green, blue) => {
+            // Check each channel is within range
+            for (channel, value) in [("red", red), ("green", green), ("blue", blue)] {
+                if !(0.0..=255.0).contains(value) {
+                    return Err(BuildError::new(
+                        span,
+                        format!(
+                            "The {} value of your rgb colour ({}) must be between 0 and 255.",
+                            channel, value
+                        ),
+                    ));
+                }
+            }
+
+            // Return colour
+            Ok(colour::from_rgb(
+                red.round() as u8,
+                green.round() as u8,
+                blue.round() as u8,
+            ))
+        }
+        ColourExpr::Hsl(hue, saturation, lightness) => {
+            // Check hue is within range
+            if !(0.0..=360.0).contains(hue) {
+                return Err(BuildError::new(
+                    span,
+                    format!(
+                        "The hue of your hsl colour ({}) must be between 0 and 360.",
+                        hue
+                    ),
+                ));
+            }
+
+            // Check saturation and lightness are within range
+            for (part, value) in [("saturation", saturation), ("lightness", lightness)] {
+                if !(0.0..=100.0).contains(value) {
+                    return Err(BuildError::new(
+                        span,
+                        format!(
+                            "The {} of your hsl colour ({}%) must be between 0% and 100%.",
+                            part, value
+                        ),
+                    ));
+                }
+            }
+
+            // Return colour
+            Ok(colour::from_hsl(
+                *hue,
+                saturation / 100.0,
+                lightness / 100.0,
+            ))
+        }
+        ColourExpr::Lighten(base, amount) => {
+            check_percentage(expression, "lighten", *amount)?;
+            Ok(colour::adjust_lightness(eval(base, rng)?, amount / 100.0))
+        }
+        ColourExpr::Darken(base, amount) => {
+            check_percentage(expression, "darken", *amount)?;
+            Ok(colour::adjust_lightness(eval(base, rng)?, -amount / 100.0))
+        }
+        ColourExpr::Mix(first, second, weight) => {
+            check_percentage(expression, "mix", *weight)?;
+            Ok(colour::mix(
+                eval(first, rng)?,
+                eval(second, rng)?,
+                weight / 100.0,
+            ))
+        }
+        ColourExpr::Random => Ok(rng.gen_range(0..=colour::MAX_COLOUR as i32)),
+        ColourExpr::Hash(text) => Ok(colour::hash(text)),
+    }
+}
+
+/// Check that the percentage given to a colour function is between 0% and 100%.
+fn check_percentage(
+    expression: &Spanned<ColourExpr>,
+    function: &str,
+    value: f64,
+) -> Result<(), BuildError> {
+    if (0.0..=100.0).contains(&value) {
+        Ok(())
+    } else {
+        Err(BuildError::new(
+            expression.span,
+            format!(
+                "The percentage given to `{}` ({}%) must be between 0% and 100%.",
+                function, value
+            ),
+        ))
+    }
+}
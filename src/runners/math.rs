@@ -5,10 +5,10 @@ pub fn eval(expression: &MathExpr) -> f64 {
     // Match expression type
     match expression {
         MathExpr::Num(x) => *x,
-        MathExpr::Neg(a) => -eval(a),
-        MathExpr::Add(a, b) => eval(a) + eval(b),
-        MathExpr::Sub(a, b) => eval(a) - eval(b),
-        MathExpr::Mul(a, b) => eval(a) * eval(b),
-        MathExpr::Div(a, b) => eval(a) / eval(b),
+        MathExpr::Neg(a) => -eval(&a.node),
+        MathExpr::Add(a, b) => eval(&a.node) + eval(&b.node),
+        MathExpr::Sub(a, b) => eval(&a.node) - eval(&b.node),
+        MathExpr::Mul(a, b) => eval(&a.node) * eval(&b.node),
+        MathExpr::Div(a, b) => eval(&a.node) / eval(&b.node),
     }
 }
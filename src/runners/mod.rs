@@ -1,5 +1,7 @@
 // Import interpreter modules
+pub mod colour;
 pub mod math;
 
 // Re-export interpreters
+pub use colour::eval as eval_colour;
 pub use math::eval;
@@ -0,0 +1,38 @@
+use crate::structs::Span;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq)]
+/// An error produced while building VeaScript output.
+pub struct BuildError {
+    /// The error message.
+    pub message: String,
+
+    /// The span of source that caused the error, if known.
+    pub span: Option<Span>,
+}
+
+impl BuildError {
+    /// Create a new build error, pointing to a given span.
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: Some(span),
+        }
+    }
+
+    /// Create a new build error that doesn't point to any source.
+    pub fn unspanned(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+        }
+    }
+}
+
+impl Display for BuildError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for BuildError {}
@@ -0,0 +1,88 @@
+use crate::enums::Overflow;
+use crate::structs::Limits;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, Default)]
+/// Options used when building VeaScript output.
+pub struct BuildOptions {
+    /// The seed used for random choices. If not set, a random seed is used for each build.
+    pub seed: Option<u64>,
+
+    /// The limits that the built output is checked against.
+    pub limits: Limits,
+
+    /// Whether to fix problems such as over-long text and duplicate components instead of
+    /// failing, reporting each fix as a warning.
+    pub lenient: bool,
+
+    /// What to do with message content that is longer than the content limit.
+    pub overflow: Overflow,
+
+    /// The directory that `#file` paths in attachments are read from. If not set, scripts can
+    /// only attach inline content.
+    pub assets: Option<PathBuf>,
+
+    /// The id of the script being built, which components with a `#handler` use to route
+    /// interactions back to it.
+    pub script_id: Option<String>,
+}
+
+impl BuildOptions {
+    /// Create a new set of build options.
+    pub fn new() -> Self {
+        Self {
+            seed: None,
+            limits: Limits::discord(),
+            lenient: false,
+            overflow: Overflow::Error,
+            assets: None,
+            script_id: None,
+        }
+    }
+
+    /// Set the seed used for random choices.
+    pub fn seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Set the limits that the built output is checked against.
+    pub fn limits(mut self, limits: Limits) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Set whether to fix problems instead of failing.
+    pub fn lenient(mut self, lenient: bool) -> Self {
+        self.lenient = lenient;
+        self
+    }
+
+    /// Set what to do with message content that is longer than the content limit.
+    pub fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+
+    /// Set the directory that attachment files are read from.
+    pub fn assets(mut self, assets: impl Into<PathBuf>) -> Self {
+        self.assets = Some(assets.into());
+        self
+    }
+
+    /// Set the id of the script being built.
+    pub fn script_id(mut self, script_id: impl Into<String>) -> Self {
+        self.script_id = Some(script_id.into());
+        self
+    }
+
+    /// Create a random number generator for a build.
+    pub fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_entropy(),
+        }
+    }
+}
@@ -0,0 +1,37 @@
+use crate::structs::Span;
+use serde::{Deserialize, Serialize};
+use std::fmt::{Display, Formatter};
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// A non-fatal problem found while building VeaScript output.
+pub struct BuildWarning {
+    /// The warning message.
+    pub message: String,
+
+    /// The span of source that caused the warning, if known.
+    pub span: Option<Span>,
+}
+
+impl BuildWarning {
+    /// Create a new build warning, pointing to a given span.
+    pub fn new(span: Span, message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: Some(span),
+        }
+    }
+
+    /// Create a new build warning that doesn't point to any source.
+    pub fn unspanned(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            span: None,
+        }
+    }
+}
+
+impl Display for BuildWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
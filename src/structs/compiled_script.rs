@@ -0,0 +1,58 @@
+use crate::enums::{CompileError, Expr};
+use crate::structs::{BuildError, BuildOptions, FileId, Spanned, VeaScriptParser};
+use crate::{build_veascript, VeaScriptOutput};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A VeaScript script that has been parsed and validated once, and can be rendered many times.
+pub struct CompiledScript {
+    /// The parsed script.
+    pub script: Vec<Spanned<Expr>>,
+}
+
+impl CompiledScript {
+    /// Parse and validate a VeaScript source file with the options it will be rendered with.
+    pub fn compile(
+        parser: &VeaScriptParser,
+        file: FileId,
+        src: &str,
+        options: &BuildOptions,
+    ) -> Result<Self, CompileError> {
+        // Parse script
+        let script = parser.parse(file, src).map_err(CompileError::Parse)?;
+
+        // Create compiled script
+        let compiled = Self { script };
+
+        // Validate script by rendering it once
+        compiled.render(options)?;
+
+        // Return compiled script
+        Ok(compiled)
+    }
+
+    /// Render the script into VeaScript output.
+    pub fn render(&self, options: &BuildOptions) -> Result<VeaScriptOutput, BuildError> {
+        build_veascript(&self.script, options)
+    }
+
+    /// Serialize the script to JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Deserialize a script from JSON.
+    pub fn from_json(json: &str) -> serde_json::Result<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Serialize the script to a compact binary form.
+    pub fn to_bytes(&self) -> bincode::Result<Vec<u8>> {
+        bincode::serialize(self)
+    }
+
+    /// Deserialize a script from its compact binary form.
+    pub fn from_bytes(bytes: &[u8]) -> bincode::Result<Self> {
+        bincode::deserialize(bytes)
+    }
+}
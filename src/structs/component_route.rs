@@ -0,0 +1,63 @@
+use std::fmt::{Display, Formatter};
+
+/// The prefix of custom ids that route component interactions to VeaScript handlers.
+pub const ROUTE_PREFIX: &str = "vs";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+/// The destination of a component interaction, stored in the component's custom id as
+/// `vs:<script id>:<handler>:<state>`.
+pub struct ComponentRoute {
+    /// The id of the script that the component belongs to.
+    pub script_id: String,
+
+    /// The name of the handler block to render when the component is used.
+    pub handler: String,
+
+    /// Extra state carried by the component, passed to the handler.
+    pub state: String,
+}
+
+impl ComponentRoute {
+    /// Create a new route to a handler in a script.
+    pub fn new(
+        script_id: impl Into<String>,
+        handler: impl Into<String>,
+        state: impl Into<String>,
+    ) -> Self {
+        Self {
+            script_id: script_id.into(),
+            handler: handler.into(),
+            state: state.into(),
+        }
+    }
+
+    /// Decode a route from a component's custom id, if it is one.
+    pub fn decode(custom_id: &str) -> Option<Self> {
+        // Remove prefix
+        let route = custom_id.strip_prefix(ROUTE_PREFIX)?.strip_prefix(':')?;
+
+        // Split into parts, with the state last since it may contain separators
+        let mut parts = route.splitn(3, ':');
+        let script_id = parts.next()?;
+        let handler = parts.next()?;
+        let state = parts.next()?;
+
+        // Return route
+        Some(Self::new(script_id, handler, state))
+    }
+
+    /// Encode the route into a custom id.
+    pub fn encode(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl Display for ComponentRoute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}:{}:{}:{}",
+            ROUTE_PREFIX, self.script_id, self.handler, self.state
+        )
+    }
+}
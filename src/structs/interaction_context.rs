@@ -0,0 +1,146 @@
+use crate::structs::{BuildError, ComponentRoute};
+use serenity::all::{ComponentInteraction, ComponentInteractionDataKind};
+
+#[derive(Debug, Clone)]
+/// The details of a component interaction that a handler is rendered with.
+///
+/// Handlers can use these details in their text with the variables `$user` (a mention),
+/// `$user_name`, `$user_id`, `$state` and `$values` (the chosen select menu values, separated by
+/// commas).
+pub struct InteractionContext {
+    /// The route decoded from the component's custom id.
+    pub route: ComponentRoute,
+
+    /// The id of the user who used the component.
+    pub user_id: u64,
+
+    /// The name of the user who used the component.
+    pub user_name: String,
+
+    /// The values chosen in a select menu, as ids for user, role and channel select menus.
+    pub values: Vec<String>,
+}
+
+impl InteractionContext {
+    /// Create a new interaction context for a route.
+    pub fn new(route: ComponentRoute, user_id: u64, user_name: impl Into<String>) -> Self {
+        Self {
+            route,
+            user_id,
+            user_name: user_name.into(),
+            values: Vec::new(),
+        }
+    }
+
+    /// Set the values chosen in a select menu.
+    pub fn values(mut self, values: Vec<String>) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// Replace the context variables in some text with their values.
+    ///
+    /// The text is read once from left to right, so variable names inside the inserted values
+    /// (such as a user named `$state`) are left as they are.
+    pub fn substitute(&self, text: &str) -> String {
+        // Get variable values, with longer names first so that `$user` doesn't match `$user_id`
+        let variables = [
+            ("$user_name", self.user_name.clone()),
+            ("$user_id", self.user_id.to_string()),
+            ("$user", format!("<@{}>", self.user_id)),
+            ("$state", self.route.state.clone()),
+            ("$values", self.values.join(", ")),
+        ];
+
+        // Copy text up to each variable, then its value
+        let mut substituted = String::with_capacity(text.len());
+        let mut rest = text;
+
+        while let Some(index) = rest.find('$') {
+            substituted.push_str(&rest[..index]);
+            rest = &rest[index..];
+
+            match variables.iter().find(|(name, _)| rest.starts_with(name)) {
+                Some((name, value)) => {
+                    substituted.push_str(value);
+                    rest = &rest[name.len()..];
+                }
+                None => {
+                    substituted.push('$');
+                    rest = &rest[1..];
+                }
+            }
+        }
+
+        // Return substituted text with the remainder
+        substituted.push_str(rest);
+        substituted
+    }
+}
+
+impl TryFrom<&ComponentInteraction> for InteractionContext {
+    type Error = BuildError;
+
+    fn try_from(value: &ComponentInteraction) -> Result<Self, Self::Error> {
+        // Decode route from the custom id
+        let route = ComponentRoute::decode(&value.data.custom_id).ok_or_else(|| {
+            BuildError::unspanned("This component wasn't created by a VeaScript handler.")
+        })?;
+
+        // Get chosen values
+        let values = match &value.data.kind {
+            ComponentInteractionDataKind::StringSelect { values } => values.clone(),
+            ComponentInteractionDataKind::UserSelect { values } => {
+                values.iter().map(ToString::to_string).collect()
+            }
+            ComponentInteractionDataKind::RoleSelect { values } => {
+                values.iter().map(ToString::to_string).collect()
+            }
+            ComponentInteractionDataKind::ChannelSelect { values } => {
+                values.iter().map(ToString::to_string).collect()
+            }
+            _ => Vec::new(),
+        };
+
+        // Return context
+        Ok(Self::new(route, value.user.id.get(), value.user.name.clone()).values(values))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn context(user_name: &str) -> InteractionContext {
+        InteractionContext::new(
+            ComponentRoute::new("script", "handler", "1st"),
+            42,
+            user_name,
+        )
+        .values(vec!["a".to_string(), "b".to_string()])
+    }
+
+    #[test]
+    fn substitutes_each_variable() {
+        assert_eq!(
+            context("vea").substitute("$user $user_name $user_id $state $values"),
+            "<@42> vea 42 1st a, b"
+        );
+    }
+
+    #[test]
+    fn leaves_unknown_variables() {
+        assert_eq!(
+            context("vea").substitute("$5 $ $unknown$"),
+            "$5 $ $unknown$"
+        );
+    }
+
+    #[test]
+    fn never_rescans_inserted_values() {
+        assert_eq!(
+            context("$user_id$state").substitute("Hi $user_name!"),
+            "Hi $user_id$state!"
+        );
+    }
+}
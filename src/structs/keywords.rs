@@ -0,0 +1,221 @@
+use crate::enums::{Keyword, Language};
+use std::collections::HashMap;
+
+/// Aliases that are always accepted alongside the canonical keywords.
+const ALIASES: &[(Keyword, &str)] = &[
+    (Keyword::Colour, "color"),
+    (Keyword::Description, "desc"),
+    (Keyword::Image, "img"),
+];
+
+/// Spanish keyword pack.
+const SPANISH: &[(Keyword, &str)] = &[
+    (Keyword::Text, "texto"),
+    (Keyword::Math, "matematicas"),
+    (Keyword::Random, "aleatorio"),
+    (Keyword::Embed, "incrustado"),
+    (Keyword::Message, "mensaje"),
+    (Keyword::Attachment, "adjunto"),
+    (Keyword::Author, "autor"),
+    (Keyword::Title, "titulo"),
+    (Keyword::Description, "descripcion"),
+    (Keyword::Colour, "color"),
+    (Keyword::Fields, "campos"),
+    (Keyword::Field, "campo"),
+    (Keyword::Image, "imagen"),
+    (Keyword::Thumbnail, "miniatura"),
+    (Keyword::Url, "enlace"),
+    (Keyword::Footer, "pie"),
+    (Keyword::Timestamp, "marca_de_tiempo"),
+    (Keyword::Name, "nombre"),
+    (Keyword::Value, "valor"),
+    (Keyword::Inline, "en_linea"),
+    (Keyword::IconUrl, "icono_url"),
+    (Keyword::Filename, "nombre_de_archivo"),
+    (Keyword::File, "archivo"),
+    (Keyword::Content, "contenido"),
+    (Keyword::Spoiler, "spoiler"),
+    (Keyword::ActionRow, "fila_de_acciones"),
+    (Keyword::Button, "boton"),
+    (Keyword::Select, "menu"),
+    (Keyword::Option, "opcion"),
+    (Keyword::Style, "estilo"),
+    (Keyword::Label, "etiqueta"),
+    (Keyword::Emoji, "emoji"),
+    (Keyword::CustomId, "id_personalizado"),
+    (Keyword::Disabled, "desactivado"),
+    (Keyword::Type, "tipo"),
+    (Keyword::Placeholder, "marcador"),
+    (Keyword::MinValues, "min_valores"),
+    (Keyword::MaxValues, "max_valores"),
+    (Keyword::Default, "predeterminado"),
+    (Keyword::OnClick, "al_hacer_clic"),
+    (Keyword::Handler, "manejador"),
+    (Keyword::State, "estado"),
+    (Keyword::Poll, "encuesta"),
+    (Keyword::Question, "pregunta"),
+    (Keyword::Answer, "respuesta"),
+    (Keyword::Duration, "duracion"),
+    (Keyword::MultiSelect, "seleccion_multiple"),
+    (Keyword::Shuffle, "mezclar"),
+    (Keyword::Settings, "ajustes"),
+    (Keyword::SuppressEmbeds, "ocultar_incrustados"),
+    (Keyword::Silent, "silencioso"),
+    (Keyword::Ephemeral, "efimero"),
+    (Keyword::Tts, "tts"),
+    (Keyword::AllowedMentions, "menciones_permitidas"),
+    (Keyword::Users, "usuarios"),
+    (Keyword::Roles, "roles"),
+    (Keyword::Everyone, "todos"),
+    (Keyword::RepliedUser, "usuario_respondido"),
+    (Keyword::Webhook, "webhook"),
+    (Keyword::Username, "nombre_de_usuario"),
+    (Keyword::AvatarUrl, "url_del_avatar"),
+    (Keyword::ThreadName, "nombre_del_hilo"),
+];
+
+/// French keyword pack.
+const FRENCH: &[(Keyword, &str)] = &[
+    (Keyword::Text, "texte"),
+    (Keyword::Math, "maths"),
+    (Keyword::Random, "aleatoire"),
+    (Keyword::Embed, "integration"),
+    (Keyword::Message, "message"),
+    (Keyword::Attachment, "piece_jointe"),
+    (Keyword::Author, "auteur"),
+    (Keyword::Title, "titre"),
+    (Keyword::Description, "description"),
+    (Keyword::Colour, "couleur"),
+    (Keyword::Fields, "champs"),
+    (Keyword::Field, "champ"),
+    (Keyword::Image, "image"),
+    (Keyword::Thumbnail, "vignette"),
+    (Keyword::Url, "lien"),
+    (Keyword::Footer, "pied"),
+    (Keyword::Timestamp, "horodatage"),
+    (Keyword::Name, "nom"),
+    (Keyword::Value, "valeur"),
+    (Keyword::Inline, "en_ligne"),
+    (Keyword::IconUrl, "icone_url"),
+    (Keyword::Filename, "nom_de_fichier"),
+    (Keyword::File, "fichier"),
+    (Keyword::Content, "contenu"),
+    (Keyword::Spoiler, "spoiler"),
+    (Keyword::ActionRow, "rangee"),
+    (Keyword::Button, "bouton"),
+    (Keyword::Select, "menu"),
+    (Keyword::Option, "option"),
+    (Keyword::Style, "style"),
+    (Keyword::Label, "libelle"),
+    (Keyword::Emoji, "emoji"),
+    (Keyword::CustomId, "id_personnalise"),
+    (Keyword::Disabled, "desactive"),
+    (Keyword::Type, "type"),
+    (Keyword::Placeholder, "indication"),
+    (Keyword::MinValues, "min_valeurs"),
+    (Keyword::MaxValues, "max_valeurs"),
+    (Keyword::Default, "par_defaut"),
+    (Keyword::OnClick, "au_clic"),
+    (Keyword::Handler, "gestionnaire"),
+    (Keyword::State, "etat"),
+    (Keyword::Poll, "sondage"),
+    (Keyword::Question, "question"),
+    (Keyword::Answer, "reponse"),
+    (Keyword::Duration, "duree"),
+    (Keyword::MultiSelect, "choix_multiple"),
+    (Keyword::Shuffle, "melanger"),
+    (Keyword::Settings, "parametres"),
+    (Keyword::SuppressEmbeds, "masquer_integres"),
+    (Keyword::Silent, "silencieux"),
+    (Keyword::Ephemeral, "ephemere"),
+    (Keyword::Tts, "tts"),
+    (Keyword::AllowedMentions, "mentions_autorisees"),
+    (Keyword::Users, "utilisateurs"),
+    (Keyword::Roles, "roles"),
+    (Keyword::Everyone, "tout_le_monde"),
+    (Keyword::RepliedUser, "utilisateur_repondu"),
+    (Keyword::Webhook, "webhook"),
+    (Keyword::Username, "nom_utilisateur"),
+    (Keyword::AvatarUrl, "url_avatar"),
+    (Keyword::ThreadName, "nom_du_fil"),
+];
+
+#[derive(Debug, Clone)]
+/// The set of spellings accepted for each VeaScript keyword when parsing.
+pub struct Keywords {
+    /// The accepted spellings for each keyword.
+    spellings: HashMap<Keyword, Vec<String>>,
+}
+
+impl Default for Keywords {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keywords {
+    /// Create a new keyword set, containing the canonical English keywords and their aliases.
+    pub fn new() -> Self {
+        // Create keyword set with canonical spellings
+        let mut keywords = Self {
+            spellings: Keyword::ALL
+                .iter()
+                .map(|keyword| (*keyword, vec![keyword.canonical().to_string()]))
+                .collect(),
+        };
+
+        // Add aliases
+        keywords.extend(ALIASES);
+
+        // Return keyword set
+        keywords
+    }
+
+    /// Create a keyword set for a given language.
+    ///
+    /// The canonical English keywords are always accepted alongside the language's keywords.
+    pub fn for_language(language: Language) -> Self {
+        // Create base keyword set
+        let mut keywords = Self::new();
+
+        // Add language pack
+        match language {
+            Language::English => {}
+            Language::Spanish => keywords.extend(SPANISH),
+            Language::French => keywords.extend(FRENCH),
+        }
+
+        // Return keyword set
+        keywords
+    }
+
+    /// Add an alternative spelling for a keyword.
+    pub fn alias(mut self, keyword: Keyword, spelling: impl Into<String>) -> Self {
+        self.add(keyword, spelling.into());
+        self
+    }
+
+    /// Get all accepted spellings for a keyword.
+    pub fn spellings(&self, keyword: Keyword) -> &[String] {
+        self.spellings
+            .get(&keyword)
+            .map(Vec::as_slice)
+            .unwrap_or_default()
+    }
+
+    /// Add a pack of spellings to the keyword set.
+    fn extend(&mut self, pack: &[(Keyword, &str)]) {
+        for (keyword, spelling) in pack {
+            self.add(*keyword, spelling.to_string());
+        }
+    }
+
+    /// Add a spelling for a keyword, if it isn't already accepted.
+    fn add(&mut self, keyword: Keyword, spelling: String) {
+        let spellings = self.spellings.entry(keyword).or_default();
+
+        if !spellings.contains(&spelling) {
+            spellings.push(spelling);
+        }
+    }
+}
@@ -0,0 +1,175 @@
+use crate::enums::LengthUnit;
+use crate::helpers::length;
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+/// The limits that built messages and embeds are checked against.
+///
+/// Custom limits can be created from a preset, e.g. `Limits { title: 100, ..Limits::discord() }`.
+pub struct Limits {
+    /// The maximum number of characters in an embed title.
+    pub title: usize,
+
+    /// The maximum number of characters in an embed description.
+    pub description: usize,
+
+    /// The maximum number of characters in an embed author name.
+    pub author_name: usize,
+
+    /// The maximum number of characters in an embed footer text.
+    pub footer_text: usize,
+
+    /// The maximum number of characters in an embed field name.
+    pub field_name: usize,
+
+    /// The maximum number of characters in an embed field value.
+    pub field_value: usize,
+
+    /// The maximum number of fields in an embed.
+    pub fields: usize,
+
+    /// The maximum number of characters in an embed, counting its title, description, author
+    /// name, footer text, field names and field values.
+    pub embed_total: usize,
+
+    /// The maximum number of characters in a message's content.
+    pub content: usize,
+
+    /// The maximum number of embeds in a message.
+    pub embeds: usize,
+
+    /// The maximum number of characters across all embeds in a message.
+    pub message_embed_total: usize,
+
+    /// The maximum number of attachments in a message.
+    pub attachments: usize,
+
+    /// The maximum size of an attachment, in bytes.
+    pub attachment_size: usize,
+
+    /// The maximum number of characters in an attachment description.
+    pub attachment_description: usize,
+
+    /// The maximum number of action rows in a message.
+    pub action_rows: usize,
+
+    /// The maximum number of buttons in an action row.
+    pub row_buttons: usize,
+
+    /// The maximum number of options in a select menu.
+    pub select_options: usize,
+
+    /// The maximum number of characters in a button label.
+    pub button_label: usize,
+
+    /// The maximum number of characters in a button or select menu custom id.
+    pub custom_id: usize,
+
+    /// The maximum number of characters in a select menu placeholder.
+    pub select_placeholder: usize,
+
+    /// The maximum number of characters in a select menu option's label, value or description.
+    pub select_option_text: usize,
+
+    /// The maximum number of characters in a poll question.
+    pub poll_question: usize,
+
+    /// The maximum number of answers in a poll.
+    pub poll_answers: usize,
+
+    /// The maximum number of characters in a poll answer.
+    pub poll_answer_text: usize,
+
+    /// The maximum duration of a poll, in hours.
+    pub poll_duration: u32,
+
+    /// The maximum number of characters in a webhook username.
+    pub webhook_username: usize,
+
+    /// The maximum number of characters in the name of a thread created by a webhook.
+    pub thread_name: usize,
+
+    /// The unit that text lengths are measured in.
+    pub unit: LengthUnit,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self::discord()
+    }
+}
+
+impl Limits {
+    /// Get Discord's limits.
+    pub const fn discord() -> Self {
+        Self {
+            title: 256,
+            description: 4096,
+            author_name: 256,
+            footer_text: 2048,
+            field_name: 256,
+            field_value: 1024,
+            fields: 25,
+            embed_total: 6000,
+            content: 2000,
+            embeds: 10,
+            message_embed_total: 6000,
+            attachments: 10,
+            attachment_size: 10 * 1024 * 1024,
+            attachment_description: 1024,
+            action_rows: 5,
+            row_buttons: 5,
+            select_options: 25,
+            button_label: 80,
+            custom_id: 100,
+            select_placeholder: 150,
+            select_option_text: 100,
+            poll_question: 300,
+            poll_answers: 10,
+            poll_answer_text: 55,
+            poll_duration: 768,
+            webhook_username: 80,
+            thread_name: 100,
+            unit: LengthUnit::CodePoints,
+        }
+    }
+
+    /// Get a stricter set of limits, suitable for scripts written by untrusted users.
+    pub const fn safe() -> Self {
+        Self {
+            title: 128,
+            description: 2048,
+            author_name: 128,
+            footer_text: 512,
+            field_name: 128,
+            field_value: 512,
+            fields: 10,
+            embed_total: 3000,
+            content: 1000,
+            embeds: 3,
+            message_embed_total: 4000,
+            attachments: 3,
+            attachment_size: 1024 * 1024,
+            attachment_description: 512,
+            action_rows: 3,
+            row_buttons: 5,
+            select_options: 10,
+            button_label: 40,
+            custom_id: 100,
+            select_placeholder: 100,
+            select_option_text: 50,
+            poll_question: 150,
+            poll_answers: 10,
+            poll_answer_text: 55,
+            poll_duration: 72,
+            webhook_username: 32,
+            thread_name: 100,
+            unit: LengthUnit::CodePoints,
+        }
+    }
+
+    /// Measure the length of some text in these limits' unit.
+    pub fn length(&self, text: &str) -> usize {
+        length::measure(text, self.unit)
+    }
+}
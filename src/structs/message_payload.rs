@@ -0,0 +1,315 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use serenity::all::Timestamp;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// A message in Discord's REST and webhook JSON format.
+pub struct MessagePayload {
+    /// The message's content.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+
+    /// The message's embeds.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub embeds: Vec<EmbedPayload>,
+
+    /// The message's attachments. The files themselves are uploaded alongside the JSON, so only
+    /// their metadata is included.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub attachments: Vec<AttachmentPayload>,
+
+    /// The message's action rows, in the format used by serenity's component models.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub components: Vec<Value>,
+
+    /// The message's poll.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub poll: Option<PollPayload>,
+
+    /// Whether the message is read aloud with text-to-speech.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tts: Option<bool>,
+
+    /// The message's flags.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u64>,
+
+    /// The kinds of mentions that notify the people they mention.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub allowed_mentions: Option<AllowedMentionsPayload>,
+
+    /// The username shown instead of the webhook's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+
+    /// The avatar shown instead of the webhook's avatar.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+
+    /// The name of the thread to create for the message, for webhooks in forum channels.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thread_name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// A message's allowed mentions in Discord's JSON format.
+pub struct AllowedMentionsPayload {
+    /// The kinds of mentions that notify, out of `users`, `roles` and `everyone`.
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub parse: Vec<String>,
+
+    /// The ids of specific users that can be notified.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub users: Vec<Value>,
+
+    /// The ids of specific roles that can be notified.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub roles: Vec<Value>,
+
+    /// Whether a reply notifies the author of the message it replies to.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replied_user: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// A poll in Discord's JSON format.
+///
+/// Unknown fields are accepted, since polls received from Discord also include their expiry and
+/// results.
+pub struct PollPayload {
+    /// The poll's question.
+    pub question: PollMediaPayload,
+
+    /// The poll's answers.
+    #[serde(default, deserialize_with = "null_as_empty")]
+    pub answers: Vec<PollAnswerPayload>,
+
+    /// How long the poll is open for, in hours.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u32>,
+
+    /// Whether more than one answer can be chosen.
+    #[serde(default)]
+    pub allow_multiselect: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// A poll answer in Discord's JSON format.
+pub struct PollAnswerPayload {
+    /// The answer's id, which is only present in polls received from Discord.
+    #[serde(default, skip_serializing)]
+    pub answer_id: Option<u32>,
+
+    /// The answer's text and emoji.
+    pub poll_media: PollMediaPayload,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// The text and emoji of a poll question or answer in Discord's JSON format.
+pub struct PollMediaPayload {
+    /// The text shown.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+
+    /// The emoji shown, which is only used for answers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emoji: Option<PollEmojiPayload>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// A poll answer's emoji in Discord's JSON format, which is either a unicode emoji's name or a
+/// custom emoji's id.
+pub struct PollEmojiPayload {
+    /// The custom emoji's id.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+
+    /// The unicode emoji, or the custom emoji's name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+
+    /// Whether the custom emoji is animated.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animated: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+/// An attachment's metadata in Discord's JSON format.
+///
+/// Unknown fields are accepted, since Discohook stores extra details about attached files.
+pub struct AttachmentPayload {
+    /// The attachment's id, which is its index among the uploaded files when sending a message.
+    #[serde(default)]
+    pub id: Value,
+
+    /// The attachment's file name.
+    #[serde(default)]
+    pub filename: String,
+
+    /// The attachment's description, used as alt text.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// An embed in Discord's JSON format.
+pub struct EmbedPayload {
+    /// The embed's title.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+
+    /// The embed's type, which is always `rich` for embeds sent by bots.
+    #[serde(default, rename = "type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+
+    /// The embed's description.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// The embed's url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The embed's ISO-8601 timestamp.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<Timestamp>,
+
+    /// The embed's colour.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub color: Option<u32>,
+
+    /// The embed's footer.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub footer: Option<EmbedFooterPayload>,
+
+    /// The embed's image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<EmbedMediaPayload>,
+
+    /// The embed's thumbnail.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub thumbnail: Option<EmbedMediaPayload>,
+
+    /// The embed's video, which can only be added by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub video: Option<Value>,
+
+    /// The embed's provider, which can only be added by Discord.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub provider: Option<Value>,
+
+    /// The embed's author.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub author: Option<EmbedAuthorPayload>,
+
+    /// The embed's fields.
+    #[serde(
+        default,
+        deserialize_with = "null_as_empty",
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub fields: Vec<EmbedFieldPayload>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// An embed author in Discord's JSON format.
+pub struct EmbedAuthorPayload {
+    /// The author's name.
+    pub name: String,
+
+    /// The author's url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+
+    /// The author's icon url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+
+    /// The proxied icon url, which is only present in embeds received from Discord.
+    #[serde(default, skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// An embed footer in Discord's JSON format.
+pub struct EmbedFooterPayload {
+    /// The footer's text.
+    pub text: String,
+
+    /// The footer's icon url.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub icon_url: Option<String>,
+
+    /// The proxied icon url, which is only present in embeds received from Discord.
+    #[serde(default, skip_serializing)]
+    pub proxy_icon_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// An embed field in Discord's JSON format.
+pub struct EmbedFieldPayload {
+    /// The field's name.
+    pub name: String,
+
+    /// The field's value.
+    pub value: String,
+
+    /// Whether or not the field is inline.
+    #[serde(default)]
+    pub inline: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(deny_unknown_fields)]
+/// An embed image or thumbnail in Discord's JSON format.
+pub struct EmbedMediaPayload {
+    /// The media's url.
+    pub url: String,
+
+    /// The proxied url, which is only present in embeds received from Discord.
+    #[serde(default, skip_serializing)]
+    pub proxy_url: Option<String>,
+
+    /// The media's height, which is only present in embeds received from Discord.
+    #[serde(default, skip_serializing)]
+    pub height: Option<u32>,
+
+    /// The media's width, which is only present in embeds received from Discord.
+    #[serde(default, skip_serializing)]
+    pub width: Option<u32>,
+}
+
+/// Deserialize a list that may be `null`, which Discord and Discohook use for empty lists.
+fn null_as_empty<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Ok(Option::<Vec<T>>::deserialize(deserializer)?.unwrap_or_default())
+}
@@ -0,0 +1,157 @@
+use crate::structs::{AllowedMentionsPayload, BuildError};
+use serde::{Deserialize, Serialize};
+use serenity::all::{CreateAllowedMentions, InteractionResponseFlags, MessageFlags};
+
+/// The message flag that hides a message's link embeds.
+pub const SUPPRESS_EMBEDS_FLAG: u64 = 1 << 2;
+
+/// The message flag that makes an interaction response only visible to the user who used it.
+pub const EPHEMERAL_FLAG: u64 = 1 << 6;
+
+/// The message flag that sends a message without push or desktop notifications.
+pub const SUPPRESS_NOTIFICATIONS_FLAG: u64 = 1 << 12;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+/// The settings a message is sent with.
+pub struct MessageSettings {
+    /// Whether link embeds are hidden.
+    pub suppress_embeds: bool,
+
+    /// Whether the message is sent without notifications.
+    pub silent: bool,
+
+    /// Whether the message is only visible to the user who used an interaction. This only applies
+    /// to interaction responses, and converting an ephemeral message into a regular or webhook
+    /// message fails.
+    pub ephemeral: bool,
+
+    /// Whether the message is read aloud with text-to-speech.
+    pub tts: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+/// The kinds of mentions in a message that notify the people they mention.
+///
+/// By default, user and role mentions notify, while `@everyone`, `@here` and the author of the
+/// replied message are not notified.
+pub struct StoredAllowedMentions {
+    /// Whether user mentions notify the mentioned users.
+    pub users: bool,
+
+    /// Whether role mentions notify the members of the mentioned roles.
+    pub roles: bool,
+
+    /// Whether `@everyone` and `@here` notify the channel.
+    pub everyone: bool,
+
+    /// Whether a reply notifies the author of the message it replies to.
+    pub replied_user: bool,
+}
+
+impl MessageSettings {
+    /// Create new message settings, with every setting turned off.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create message settings from Discord's message flags and text-to-speech setting.
+    pub fn from_flags(flags: u64, tts: bool) -> Self {
+        Self {
+            suppress_embeds: flags & SUPPRESS_EMBEDS_FLAG != 0,
+            silent: flags & SUPPRESS_NOTIFICATIONS_FLAG != 0,
+            ephemeral: flags & EPHEMERAL_FLAG != 0,
+            tts,
+        }
+    }
+
+    /// Get the settings as Discord's message flags.
+    pub fn flags(&self) -> u64 {
+        [
+            (self.suppress_embeds, SUPPRESS_EMBEDS_FLAG),
+            (self.silent, SUPPRESS_NOTIFICATIONS_FLAG),
+            (self.ephemeral, EPHEMERAL_FLAG),
+        ]
+        .into_iter()
+        .filter(|(set, _)| *set)
+        .fold(0, |flags, (_, flag)| flags | flag)
+    }
+
+    /// Get the flags for a regular message, which can't be ephemeral.
+    pub fn message_flags(&self) -> MessageFlags {
+        MessageFlags::from_bits_truncate(self.flags() & !EPHEMERAL_FLAG)
+    }
+
+    /// Get the flags for an interaction response.
+    pub fn interaction_flags(&self) -> InteractionResponseFlags {
+        InteractionResponseFlags::from_bits_truncate(self.flags())
+    }
+}
+
+impl Default for StoredAllowedMentions {
+    fn default() -> Self {
+        Self {
+            users: true,
+            roles: true,
+            everyone: false,
+            replied_user: false,
+        }
+    }
+}
+
+impl StoredAllowedMentions {
+    /// Create new allowed mentions, using the default of notifying users and roles only.
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl From<StoredAllowedMentions> for CreateAllowedMentions {
+    fn from(value: StoredAllowedMentions) -> Self {
+        CreateAllowedMentions::new()
+            .all_users(value.users)
+            .all_roles(value.roles)
+            .everyone(value.everyone)
+            .replied_user(value.replied_user)
+    }
+}
+
+impl From<&StoredAllowedMentions> for AllowedMentionsPayload {
+    fn from(value: &StoredAllowedMentions) -> Self {
+        Self {
+            parse: [
+                (value.users, "users"),
+                (value.roles, "roles"),
+                (value.everyone, "everyone"),
+            ]
+            .into_iter()
+            .filter(|(allowed, _)| *allowed)
+            .map(|(_, kind)| kind.to_string())
+            .collect(),
+            users: Vec::new(),
+            roles: Vec::new(),
+            replied_user: Some(value.replied_user),
+        }
+    }
+}
+
+impl TryFrom<AllowedMentionsPayload> for StoredAllowedMentions {
+    type Error = BuildError;
+
+    fn try_from(value: AllowedMentionsPayload) -> Result<Self, Self::Error> {
+        // Check for specific users and roles, which can't be imported
+        if !value.users.is_empty() || !value.roles.is_empty() {
+            return Err(BuildError::unspanned(
+                "Allowed mentions for specific users or roles can't be imported.",
+            ));
+        }
+
+        // Return allowed mentions
+        let allowed = |kind: &str| value.parse.iter().any(|parse| parse == kind);
+        Ok(Self {
+            users: allowed("users"),
+            roles: allowed("roles"),
+            everyone: allowed("everyone"),
+            replied_user: value.replied_user.unwrap_or(false),
+        })
+    }
+}
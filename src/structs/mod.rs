@@ -1,5 +1,41 @@
 // Import modules
+pub mod build_error;
+pub mod build_options;
+pub mod build_warning;
+pub mod compiled_script;
+pub mod component_route;
+pub mod interaction_context;
+pub mod keywords;
+pub mod limits;
+pub mod message_payload;
+pub mod message_settings;
+pub mod parser;
+pub mod span;
+pub mod stored_attachment;
+pub mod stored_component;
 pub mod stored_embed;
+pub mod stored_message;
+pub mod stored_poll;
+pub mod stored_webhook;
+pub mod veascript_output;
 
 // Re-export structs
+pub use build_error::*;
+pub use build_options::*;
+pub use build_warning::*;
+pub use compiled_script::*;
+pub use component_route::*;
+pub use interaction_context::*;
+pub use keywords::*;
+pub use limits::*;
+pub use message_payload::*;
+pub use message_settings::*;
+pub use parser::*;
+pub use span::*;
+pub use stored_attachment::*;
+pub use stored_component::*;
 pub use stored_embed::*;
+pub use stored_message::*;
+pub use stored_poll::*;
+pub use stored_webhook::*;
+pub use veascript_output::*;
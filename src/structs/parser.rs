@@ -0,0 +1,39 @@
+use crate::enums::Expr;
+use crate::parse_veascript_with;
+use crate::parsers::{self, ParseError, SyncParser};
+use crate::structs::{FileId, Keywords, Spanned};
+use chumsky::Parser;
+use std::sync::{Arc, OnceLock};
+
+#[derive(Clone)]
+/// A constructed VeaScript parser, which can be reused and shared between threads.
+pub struct VeaScriptParser {
+    /// The constructed parser.
+    parser: SyncParser<Vec<Spanned<Expr>>>,
+}
+
+impl Default for VeaScriptParser {
+    fn default() -> Self {
+        Self::new(&Keywords::new())
+    }
+}
+
+impl VeaScriptParser {
+    /// Construct a new parser, using a given set of keywords.
+    pub fn new(keywords: &Keywords) -> Self {
+        Self {
+            parser: Arc::new(parse_veascript_with(keywords)),
+        }
+    }
+
+    /// Get a shared parser using the default set of keywords.
+    pub fn shared() -> &'static Self {
+        static PARSER: OnceLock<VeaScriptParser> = OnceLock::new();
+        PARSER.get_or_init(Self::default)
+    }
+
+    /// Parse a VeaScript source file.
+    pub fn parse(&self, file: FileId, src: &str) -> Result<Vec<Spanned<Expr>>, Vec<ParseError>> {
+        Parser::parse(&self.parser, parsers::stream(file, src))
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// An identifier for a VeaScript source file.
+pub type FileId = usize;
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+/// A range of characters in a VeaScript source file.
+pub struct Span {
+    /// The file that the span is in.
+    pub file: FileId,
+
+    /// The character offset that the span starts at.
+    pub start: usize,
+
+    /// The character offset that the span ends at (exclusive).
+    pub end: usize,
+}
+
+impl Span {
+    /// Create a new span.
+    pub fn new(file: FileId, range: Range<usize>) -> Self {
+        Self {
+            file,
+            start: range.start,
+            end: range.end,
+        }
+    }
+
+    /// Get the smallest span that covers both this span and another span.
+    pub fn union(&self, other: Span) -> Self {
+        Self {
+            file: self.file,
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    /// Get the span's character range.
+    pub fn range(&self) -> Range<usize> {
+        self.start..self.end
+    }
+}
+
+impl chumsky::Span for Span {
+    type Context = FileId;
+    type Offset = usize;
+
+    fn new(context: Self::Context, range: Range<Self::Offset>) -> Self {
+        Span::new(context, range)
+    }
+
+    fn context(&self) -> Self::Context {
+        self.file
+    }
+
+    fn start(&self) -> Self::Offset {
+        self.start
+    }
+
+    fn end(&self) -> Self::Offset {
+        self.end
+    }
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+/// A VeaScript AST node, along with the span of source it was parsed from.
+pub struct Spanned<T> {
+    /// The AST node.
+    pub node: T,
+
+    /// The span of the node.
+    pub span: Span,
+}
+
+impl<T> Spanned<T> {
+    /// Wrap an AST node with a span.
+    pub fn new(node: T, span: Span) -> Self {
+        Self { node, span }
+    }
+}
@@ -0,0 +1,84 @@
+use crate::structs::AttachmentPayload;
+use serde::{Deserialize, Serialize};
+use serenity::all::CreateAttachment;
+
+/// The prefix Discord uses to mark an attachment as a spoiler.
+pub const SPOILER_PREFIX: &str = "SPOILER_";
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored file that is attached to a message.
+pub struct StoredAttachment {
+    /// The attachment's file name, as written in the script.
+    pub filename: String,
+
+    /// The attachment's contents.
+    pub data: Vec<u8>,
+
+    /// The attachment's description, used as alt text.
+    pub description: Option<String>,
+
+    /// Whether the attachment is hidden as a spoiler.
+    pub spoiler: bool,
+}
+
+impl StoredAttachment {
+    /// Create a new attachment.
+    pub fn new(filename: impl Into<String>, data: impl Into<Vec<u8>>) -> Self {
+        Self {
+            filename: filename.into(),
+            data: data.into(),
+            description: None,
+            spoiler: false,
+        }
+    }
+
+    /// Set the attachment's description.
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Set whether the attachment is hidden as a spoiler.
+    pub fn spoiler(mut self, spoiler: bool) -> Self {
+        self.spoiler = spoiler;
+        self
+    }
+
+    /// Get the file name the attachment is uploaded with, which is prefixed when it is a spoiler.
+    pub fn upload_filename(&self) -> String {
+        if self.spoiler {
+            format!("{}{}", SPOILER_PREFIX, self.filename)
+        } else {
+            self.filename.clone()
+        }
+    }
+
+    /// Check whether a file name refers to this attachment, either as written or as uploaded.
+    pub fn is_named(&self, name: &str) -> bool {
+        name == self.filename || name == self.upload_filename()
+    }
+}
+
+impl From<StoredAttachment> for CreateAttachment {
+    fn from(value: StoredAttachment) -> Self {
+        // Create attachment
+        let filename = value.upload_filename();
+        let attachment = CreateAttachment::bytes(value.data, filename);
+
+        // Add description if there is one
+        match value.description {
+            Some(description) => attachment.description(description),
+            None => attachment,
+        }
+    }
+}
+
+impl From<&StoredAttachment> for AttachmentPayload {
+    fn from(value: &StoredAttachment) -> Self {
+        Self {
+            id: 0.into(),
+            filename: value.upload_filename(),
+            description: value.description.clone(),
+        }
+    }
+}
@@ -0,0 +1,625 @@
+use crate::enums::{ButtonStyle, SelectKind};
+use crate::helpers::validation;
+use crate::structs::{BuildError, Limits};
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ActionRow, ActionRowComponent, Button, ButtonKind, ButtonStyle as SerenityButtonStyle,
+    ComponentType, CreateActionRow, CreateButton, CreateSelectMenu, CreateSelectMenuKind,
+    CreateSelectMenuOption, ReactionType, SelectMenu, SelectMenuOption,
+};
+use std::collections::HashSet;
+
+/// The maximum number of values that can be chosen in a select menu.
+pub const MAX_SELECT_VALUES: u8 = 25;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored row of message components, which holds either buttons or a single select menu.
+pub enum StoredActionRow {
+    /// A row of buttons.
+    Buttons(Vec<StoredButton>),
+
+    /// A row with a select menu.
+    Select(StoredSelect),
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored message button.
+pub struct StoredButton {
+    /// The button's style.
+    pub style: ButtonStyle,
+
+    /// The button's label.
+    pub label: Option<String>,
+
+    /// The button's emoji, either a unicode emoji or a custom emoji such as `<:name:id>`.
+    pub emoji: Option<String>,
+
+    /// The id sent to the bot when the button is clicked, for buttons that aren't links.
+    pub custom_id: Option<String>,
+
+    /// The URL opened when the button is clicked, for link buttons.
+    pub url: Option<String>,
+
+    /// Whether the button is disabled.
+    pub disabled: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored message select menu.
+pub struct StoredSelect {
+    /// The kind of values the select menu lets users choose from.
+    pub kind: SelectKind,
+
+    /// The id sent to the bot when values are chosen.
+    pub custom_id: String,
+
+    /// The text shown when nothing is chosen.
+    pub placeholder: Option<String>,
+
+    /// The minimum number of values that must be chosen.
+    pub min_values: Option<u8>,
+
+    /// The maximum number of values that can be chosen.
+    pub max_values: Option<u8>,
+
+    /// Whether the select menu is disabled.
+    pub disabled: bool,
+
+    /// The select menu's options, for string select menus.
+    pub options: Vec<StoredSelectOption>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored select menu option.
+pub struct StoredSelectOption {
+    /// The option's label.
+    pub label: String,
+
+    /// The value sent to the bot when the option is chosen.
+    pub value: String,
+
+    /// The option's description.
+    pub description: Option<String>,
+
+    /// The option's emoji, either a unicode emoji or a custom emoji such as `<:name:id>`.
+    pub emoji: Option<String>,
+
+    /// Whether the option is chosen by default.
+    pub default: bool,
+}
+
+impl StoredActionRow {
+    /// Get the custom ids of the components in the row.
+    pub fn custom_ids(&self) -> Vec<&str> {
+        match self {
+            StoredActionRow::Buttons(buttons) => buttons
+                .iter()
+                .filter_map(|button| button.custom_id.as_deref())
+                .collect(),
+            StoredActionRow::Select(select) => vec![select.custom_id.as_str()],
+        }
+    }
+
+    /// Check that the action row is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        match self {
+            StoredActionRow::Buttons(buttons) => {
+                // Check if the row is empty
+                if buttons.is_empty() {
+                    return Err(BuildError::unspanned(
+                        "An action row needs at least one button or select menu.",
+                    ));
+                }
+
+                // Check if there are too many buttons
+                if buttons.len() > limits.row_buttons {
+                    return Err(BuildError::unspanned(format!(
+                        "Your action row has {} buttons. An action row can only have a maximum of {} buttons.",
+                        buttons.len(),
+                        limits.row_buttons
+                    )));
+                }
+
+                // Check each button
+                buttons
+                    .iter()
+                    .try_for_each(|button| button.validate(limits))
+            }
+            StoredActionRow::Select(select) => select.validate(limits),
+        }
+    }
+}
+
+impl StoredButton {
+    /// Create a new button with a given style.
+    pub fn new(style: ButtonStyle) -> Self {
+        Self {
+            style,
+            label: None,
+            emoji: None,
+            custom_id: None,
+            url: None,
+            disabled: false,
+        }
+    }
+
+    /// Check that the button is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check that the button has something to show
+        if self.label.is_none() && self.emoji.is_none() {
+            return Err(BuildError::unspanned(
+                "A button needs a #label or an #emoji.",
+            ));
+        }
+
+        // Check that the button has the right kind of target for its style
+        if self.style == ButtonStyle::Link {
+            let url = self
+                .url
+                .as_ref()
+                .ok_or_else(|| BuildError::unspanned("A link button needs a #url."))?;
+            validation::url(url, "button URL").map_err(BuildError::unspanned)?;
+
+            if self.custom_id.is_some() {
+                return Err(BuildError::unspanned(
+                    "A link button can't have a #custom_id.",
+                ));
+            }
+        } else {
+            let custom_id = self.custom_id.as_ref().ok_or_else(|| {
+                BuildError::unspanned(format!(
+                    "A {} button needs a #custom_id.",
+                    self.style.name()
+                ))
+            })?;
+            check_custom_id(custom_id, limits)?;
+
+            if self.url.is_some() {
+                return Err(BuildError::unspanned(
+                    "Only link buttons can have a #url. Use `#style: link,` to make a link button.",
+                ));
+            }
+        }
+
+        // Check label and emoji
+        if let Some(label) = &self.label {
+            check_length(label, "button label", limits.button_label, limits)?;
+        }
+
+        if let Some(emoji) = &self.emoji {
+            check_emoji(emoji)?;
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+impl StoredSelect {
+    /// Create a new select menu with a given kind and custom id.
+    pub fn new(kind: SelectKind, custom_id: impl Into<String>) -> Self {
+        Self {
+            kind,
+            custom_id: custom_id.into(),
+            placeholder: None,
+            min_values: None,
+            max_values: None,
+            disabled: false,
+            options: Vec::new(),
+        }
+    }
+
+    /// Check that the select menu is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check custom id and placeholder
+        check_custom_id(&self.custom_id, limits)?;
+
+        if let Some(placeholder) = &self.placeholder {
+            check_length(
+                placeholder,
+                "select menu placeholder",
+                limits.select_placeholder,
+                limits,
+            )?;
+        }
+
+        // Check options
+        if self.kind == SelectKind::String {
+            if self.options.is_empty() {
+                return Err(BuildError::unspanned(
+                    "A string select menu needs at least one #option.",
+                ));
+            }
+
+            if self.options.len() > limits.select_options {
+                return Err(BuildError::unspanned(format!(
+                    "Your select menu has {} options. A select menu can only have a maximum of {} options.",
+                    self.options.len(),
+                    limits.select_options
+                )));
+            }
+        } else if !self.options.is_empty() {
+            return Err(BuildError::unspanned(format!(
+                "Only string select menus can have options, but this is a {} select menu.",
+                self.kind.name()
+            )));
+        }
+
+        let mut values = HashSet::new();
+        for option in &self.options {
+            option.validate(limits)?;
+
+            if !values.insert(option.value.as_str()) {
+                return Err(BuildError::unspanned(format!(
+                    "The value {} is used by more than one option in your select menu.",
+                    option.value
+                )));
+            }
+        }
+
+        // Check the number of values that can be chosen
+        let min_values = self.min_values.unwrap_or(1);
+        let max_values = self.max_values.unwrap_or(1);
+
+        if max_values == 0 || max_values > MAX_SELECT_VALUES || min_values > MAX_SELECT_VALUES {
+            return Err(BuildError::unspanned(format!(
+                "A select menu's #min_values must be between 0 and {}, and its #max_values between 1 and {}.",
+                MAX_SELECT_VALUES, MAX_SELECT_VALUES
+            )));
+        }
+
+        if min_values > max_values {
+            return Err(BuildError::unspanned(format!(
+                "Your select menu's #min_values ({}) is above its #max_values ({}).",
+                min_values, max_values
+            )));
+        }
+
+        if self.kind == SelectKind::String && max_values as usize > self.options.len() {
+            return Err(BuildError::unspanned(format!(
+                "Your select menu's #max_values ({}) is above its number of options ({}).",
+                max_values,
+                self.options.len()
+            )));
+        }
+
+        let defaults = self.options.iter().filter(|option| option.default).count();
+
+        if defaults > max_values as usize {
+            return Err(BuildError::unspanned(format!(
+                "Your select menu has {} default options, but only {} can be chosen.",
+                defaults, max_values
+            )));
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+impl StoredSelectOption {
+    /// Create a new select menu option.
+    pub fn new(label: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            value: value.into(),
+            description: None,
+            emoji: None,
+            default: false,
+        }
+    }
+
+    /// Check that the option is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check label, value and description
+        if self.label.is_empty() || self.value.is_empty() {
+            return Err(BuildError::unspanned(
+                "A select menu option needs a #label and a #value.",
+            ));
+        }
+
+        check_length(
+            &self.label,
+            "option label",
+            limits.select_option_text,
+            limits,
+        )?;
+        check_length(
+            &self.value,
+            "option value",
+            limits.select_option_text,
+            limits,
+        )?;
+
+        if let Some(description) = &self.description {
+            check_length(
+                description,
+                "option description",
+                limits.select_option_text,
+                limits,
+            )?;
+        }
+
+        // Check emoji
+        if let Some(emoji) = &self.emoji {
+            check_emoji(emoji)?;
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+/// Check that some text is within its limit.
+pub(super) fn check_length(
+    text: &str,
+    name: &str,
+    max: usize,
+    limits: &Limits,
+) -> Result<(), BuildError> {
+    let text_length = limits.length(text);
+
+    if text_length > max {
+        Err(BuildError::unspanned(format!(
+            "The length of your {} ({}) is above the maximum of {} characters.",
+            name, text_length, max
+        )))
+    } else {
+        Ok(())
+    }
+}
+
+/// Check that a custom id is set and within its limit.
+fn check_custom_id(custom_id: &str, limits: &Limits) -> Result<(), BuildError> {
+    if custom_id.is_empty() {
+        return Err(BuildError::unspanned("A #custom_id can't be empty."));
+    }
+
+    check_length(custom_id, "custom id", limits.custom_id, limits)
+}
+
+/// Check that an emoji is a unicode emoji or a custom emoji such as `<:name:id>`.
+pub(super) fn check_emoji(emoji: &str) -> Result<(), BuildError> {
+    ReactionType::try_from(emoji).map(|_| ()).map_err(|_| {
+        BuildError::unspanned(format!(
+            "Invalid emoji provided: {}. Use a unicode emoji or a custom emoji like <:name:id>.",
+            emoji
+        ))
+    })
+}
+
+impl From<StoredActionRow> for CreateActionRow {
+    fn from(value: StoredActionRow) -> Self {
+        match value {
+            StoredActionRow::Buttons(buttons) => {
+                CreateActionRow::Buttons(buttons.into_iter().map(CreateButton::from).collect())
+            }
+            StoredActionRow::Select(select) => CreateActionRow::SelectMenu(select.into()),
+        }
+    }
+}
+
+impl From<StoredButton> for CreateButton {
+    fn from(value: StoredButton) -> Self {
+        // Create button for its style
+        let mut button = match value.style {
+            ButtonStyle::Link => CreateButton::new_link(value.url.unwrap_or_default()),
+            style => CreateButton::new(value.custom_id.unwrap_or_default()).style(match style {
+                ButtonStyle::Secondary => SerenityButtonStyle::Secondary,
+                ButtonStyle::Success => SerenityButtonStyle::Success,
+                ButtonStyle::Danger => SerenityButtonStyle::Danger,
+                _ => SerenityButtonStyle::Primary,
+            }),
+        };
+
+        // Add label and emoji
+        if let Some(label) = value.label {
+            button = button.label(label);
+        }
+
+        if let Some(emoji) = value
+            .emoji
+            .and_then(|emoji| ReactionType::try_from(emoji).ok())
+        {
+            button = button.emoji(emoji);
+        }
+
+        // Return button
+        button.disabled(value.disabled)
+    }
+}
+
+impl From<StoredSelect> for CreateSelectMenu {
+    fn from(value: StoredSelect) -> Self {
+        // Get select menu kind
+        let kind = match value.kind {
+            SelectKind::String => CreateSelectMenuKind::String {
+                options: value
+                    .options
+                    .into_iter()
+                    .map(CreateSelectMenuOption::from)
+                    .collect(),
+            },
+            SelectKind::User => CreateSelectMenuKind::User {
+                default_users: None,
+            },
+            SelectKind::Role => CreateSelectMenuKind::Role {
+                default_roles: None,
+            },
+            SelectKind::Channel => CreateSelectMenuKind::Channel {
+                channel_types: None,
+                default_channels: None,
+            },
+        };
+
+        // Create select menu
+        let mut select = CreateSelectMenu::new(value.custom_id, kind).disabled(value.disabled);
+
+        if let Some(placeholder) = value.placeholder {
+            select = select.placeholder(placeholder);
+        }
+
+        if let Some(min_values) = value.min_values {
+            select = select.min_values(min_values);
+        }
+
+        if let Some(max_values) = value.max_values {
+            select = select.max_values(max_values);
+        }
+
+        // Return select menu
+        select
+    }
+}
+
+impl From<StoredSelectOption> for CreateSelectMenuOption {
+    fn from(value: StoredSelectOption) -> Self {
+        // Create option
+        let mut option =
+            CreateSelectMenuOption::new(value.label, value.value).default_selection(value.default);
+
+        // Add description and emoji
+        if let Some(description) = value.description {
+            option = option.description(description);
+        }
+
+        if let Some(emoji) = value
+            .emoji
+            .and_then(|emoji| ReactionType::try_from(emoji).ok())
+        {
+            option = option.emoji(emoji);
+        }
+
+        // Return option
+        option
+    }
+}
+
+impl TryFrom<ActionRow> for StoredActionRow {
+    type Error = BuildError;
+
+    fn try_from(value: ActionRow) -> Result<Self, Self::Error> {
+        // Split components into buttons and select menus
+        let mut buttons = Vec::new();
+        let mut selects = Vec::new();
+
+        for component in value.components {
+            match component {
+                ActionRowComponent::Button(button) => buttons.push(button.try_into()?),
+                ActionRowComponent::SelectMenu(select) => selects.push(select.try_into()?),
+                _ => {
+                    return Err(BuildError::unspanned(
+                        "Text inputs can only be used in modals, so they can't be imported.",
+                    ))
+                }
+            }
+        }
+
+        // Create row, which can't mix buttons and select menus
+        match (buttons.is_empty(), selects.len()) {
+            (_, 0) => Ok(StoredActionRow::Buttons(buttons)),
+            (true, 1) => Ok(StoredActionRow::Select(selects.remove(0))),
+            _ => Err(BuildError::unspanned(
+                "An action row can only have buttons or a single select menu.",
+            )),
+        }
+    }
+}
+
+impl TryFrom<Button> for StoredButton {
+    type Error = BuildError;
+
+    fn try_from(value: Button) -> Result<Self, Self::Error> {
+        // Get style and target
+        let mut button = match value.data {
+            ButtonKind::Link { url } => {
+                let mut button = StoredButton::new(ButtonStyle::Link);
+                button.url = Some(url);
+                button
+            }
+            ButtonKind::NonLink { custom_id, style } => {
+                let mut button = StoredButton::new(match style {
+                    SerenityButtonStyle::Primary => ButtonStyle::Primary,
+                    SerenityButtonStyle::Secondary => ButtonStyle::Secondary,
+                    SerenityButtonStyle::Success => ButtonStyle::Success,
+                    SerenityButtonStyle::Danger => ButtonStyle::Danger,
+                    _ => {
+                        return Err(BuildError::unspanned(
+                            "Buttons with an unknown style can't be imported.",
+                        ))
+                    }
+                });
+                button.custom_id = Some(custom_id);
+                button
+            }
+            ButtonKind::Premium { .. } => {
+                return Err(BuildError::unspanned("Premium buttons can't be imported."))
+            }
+        };
+
+        // Add label, emoji and disabled state
+        button.label = value.label;
+        button.emoji = value.emoji.map(|emoji| emoji.to_string());
+        button.disabled = value.disabled;
+
+        // Return button
+        Ok(button)
+    }
+}
+
+impl TryFrom<SelectMenu> for StoredSelect {
+    type Error = BuildError;
+
+    fn try_from(value: SelectMenu) -> Result<Self, Self::Error> {
+        // Get kind
+        let kind = match value.kind {
+            ComponentType::StringSelect => SelectKind::String,
+            ComponentType::UserSelect => SelectKind::User,
+            ComponentType::RoleSelect => SelectKind::Role,
+            ComponentType::ChannelSelect => SelectKind::Channel,
+            _ => {
+                return Err(BuildError::unspanned(
+                    "Only string, user, role and channel select menus can be imported.",
+                ))
+            }
+        };
+
+        // Check for channel type filters, which can't be written in VeaScript
+        if !value.channel_types.is_empty() {
+            return Err(BuildError::unspanned(
+                "Select menus with channel type filters can't be imported.",
+            ));
+        }
+
+        // Create select menu
+        let custom_id = value
+            .custom_id
+            .ok_or_else(|| BuildError::unspanned("A select menu needs a custom id."))?;
+        let mut select = StoredSelect::new(kind, custom_id);
+
+        select.placeholder = value.placeholder;
+        select.min_values = value.min_values;
+        select.max_values = value.max_values;
+        select.disabled = value.disabled;
+        select.options = value
+            .options
+            .into_iter()
+            .map(StoredSelectOption::from)
+            .collect();
+
+        // Return select menu
+        Ok(select)
+    }
+}
+
+impl From<SelectMenuOption> for StoredSelectOption {
+    fn from(value: SelectMenuOption) -> Self {
+        Self {
+            label: value.label,
+            value: value.value,
+            description: value.description,
+            emoji: value.emoji.map(|emoji| emoji.to_string()),
+            default: value.default,
+        }
+    }
+}
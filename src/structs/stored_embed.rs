@@ -1,5 +1,14 @@
+use crate::enums::LengthUnit;
+use crate::helpers::{colour, hexadecimal, length, validation};
+use crate::structs::{
+    BuildError, EmbedAuthorPayload, EmbedFieldPayload, EmbedFooterPayload, EmbedMediaPayload,
+    EmbedPayload, Limits,
+};
 use serde::{Deserialize, Serialize};
-use serenity::all::{Colour, CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, Timestamp};
+use serenity::all::{
+    Colour, CreateEmbed, CreateEmbedAuthor, CreateEmbedFooter, Embed, EmbedAuthor, EmbedField,
+    EmbedFooter, Timestamp,
+};
 
 #[derive(Serialize, Deserialize, Debug)]
 /// A stored embed that can be used by the bot.
@@ -35,8 +44,10 @@ pub struct StoredEmbed {
     pub timestamp: Option<i64>,
 }
 
-impl From<StoredEmbed> for CreateEmbed {
-    fn from(value: StoredEmbed) -> Self {
+impl TryFrom<StoredEmbed> for CreateEmbed {
+    type Error = BuildError;
+
+    fn try_from(value: StoredEmbed) -> Result<Self, Self::Error> {
         // Create base embed
         let mut embed = CreateEmbed::new().colour(value.colour);
 
@@ -82,14 +93,174 @@ impl From<StoredEmbed> for CreateEmbed {
 
         // Add embed timestamp if needed
         if let Some(timestamp) = value.timestamp {
-            embed = embed.timestamp(
-                Timestamp::from_unix_timestamp(timestamp)
-                    .expect("Stored embed should always contain a valid timestamp"),
-            )
+            embed = embed.timestamp(to_timestamp(timestamp)?)
         }
 
         // Return embed
-        embed
+        Ok(embed)
+    }
+}
+
+impl TryFrom<Embed> for StoredEmbed {
+    type Error = BuildError;
+
+    fn try_from(value: Embed) -> Result<Self, Self::Error> {
+        // Get parts that can only be added by Discord
+        let unsupported: Vec<&str> = [
+            ("video", value.video.is_some()),
+            ("provider", value.provider.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, present)| *present)
+        .map(|(part, _)| part)
+        .collect();
+
+        // Check for unsupported parts
+        if !unsupported.is_empty() {
+            return Err(BuildError::unspanned(format!(
+                "This embed can't be stored because it has a {}, which can only be added by Discord.",
+                unsupported.join(" and ")
+            )));
+        }
+
+        // Check colour
+        let colour = value.colour.map(|colour| colour.0 as i64).unwrap_or(0);
+
+        if colour > colour::MAX_COLOUR {
+            return Err(BuildError::unspanned(format!(
+                "Your colour value (#{}) is greater than the limit of #FFFFFF.",
+                hexadecimal::hex_to_str(colour)
+            )));
+        }
+
+        // Return stored embed
+        Ok(Self {
+            author: value.author.map(StoredEmbedAuthor::from),
+            colour: colour as i32,
+            title: value.title,
+            url: value.url,
+            description: value.description,
+            fields: value
+                .fields
+                .into_iter()
+                .map(StoredEmbedField::from)
+                .collect(),
+            image_url: value.image.map(|image| image.url),
+            thumbnail_url: value.thumbnail.map(|thumbnail| thumbnail.url),
+            footer: value.footer.map(StoredEmbedFooter::from),
+            timestamp: value.timestamp.map(|timestamp| timestamp.unix_timestamp()),
+        })
+    }
+}
+
+impl TryFrom<EmbedPayload> for StoredEmbed {
+    type Error = BuildError;
+
+    fn try_from(value: EmbedPayload) -> Result<Self, Self::Error> {
+        // Check embed type
+        if let Some(kind) = value.kind.as_ref().filter(|kind| *kind != "rich") {
+            return Err(BuildError::unspanned(format!(
+                "Only rich embeds can be imported, but this embed has the type `{}`.",
+                kind
+            )));
+        }
+
+        // Get parts that can only be added by Discord
+        let unsupported: Vec<&str> = [
+            ("video", value.video.is_some()),
+            ("provider", value.provider.is_some()),
+        ]
+        .into_iter()
+        .filter(|(_, present)| *present)
+        .map(|(part, _)| part)
+        .collect();
+
+        // Check for unsupported parts
+        if !unsupported.is_empty() {
+            return Err(BuildError::unspanned(format!(
+                "This embed can't be imported because it has a {}, which can only be added by Discord.",
+                unsupported.join(" and ")
+            )));
+        }
+
+        // Check colour
+        let colour = value.color.unwrap_or(0) as i64;
+
+        if colour > colour::MAX_COLOUR {
+            return Err(BuildError::unspanned(format!(
+                "Your colour value (#{}) is greater than the limit of #FFFFFF.",
+                hexadecimal::hex_to_str(colour)
+            )));
+        }
+
+        // Return stored embed
+        Ok(Self {
+            author: value.author.map(StoredEmbedAuthor::from),
+            colour: colour as i32,
+            title: value.title,
+            url: value.url,
+            description: value.description,
+            fields: value
+                .fields
+                .into_iter()
+                .map(StoredEmbedField::from)
+                .collect(),
+            image_url: value.image.map(|image| image.url),
+            thumbnail_url: value.thumbnail.map(|thumbnail| thumbnail.url),
+            footer: value.footer.map(StoredEmbedFooter::from),
+            timestamp: value.timestamp.map(|timestamp| timestamp.unix_timestamp()),
+        })
+    }
+}
+
+impl TryFrom<&StoredEmbed> for EmbedPayload {
+    type Error = BuildError;
+
+    fn try_from(value: &StoredEmbed) -> Result<Self, Self::Error> {
+        Ok(Self {
+            title: value.title.clone(),
+            kind: None,
+            description: value.description.clone(),
+            url: value.url.clone(),
+            timestamp: value.timestamp.map(to_timestamp).transpose()?,
+            color: (value.colour != 0).then_some(value.colour as u32),
+            footer: value.footer.as_ref().map(|footer| EmbedFooterPayload {
+                text: footer.text.clone(),
+                icon_url: footer.icon_url.clone(),
+                proxy_icon_url: None,
+            }),
+            image: value.image_url.as_ref().map(|url| EmbedMediaPayload {
+                url: url.clone(),
+                ..Default::default()
+            }),
+            thumbnail: value.thumbnail_url.as_ref().map(|url| EmbedMediaPayload {
+                url: url.clone(),
+                ..Default::default()
+            }),
+            video: None,
+            provider: None,
+            author: value.author.as_ref().map(|author| EmbedAuthorPayload {
+                name: author.name.clone(),
+                url: author.url.clone(),
+                icon_url: author.icon_url.clone(),
+                proxy_icon_url: None,
+            }),
+            fields: value
+                .fields
+                .iter()
+                .map(|field| EmbedFieldPayload {
+                    name: field.name.clone(),
+                    value: field.value.clone(),
+                    inline: field.inline,
+                })
+                .collect(),
+        })
+    }
+}
+
+impl Default for StoredEmbed {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -181,35 +352,130 @@ impl StoredEmbed {
 }
 
 impl StoredEmbed {
-    /// Get the total number of characters in this embed.
+    /// Check the embed against the given limits, returning every violation found.
+    pub fn validate(&self, limits: &Limits) -> Result<(), Vec<BuildError>> {
+        // Create errors vector
+        let mut errors = Vec::new();
+
+        // Check author
+        if let Some(author) = &self.author {
+            if let Err(mut author_errors) = author.validate(limits) {
+                errors.append(&mut author_errors);
+            }
+        }
+
+        // Check colour
+        if !(0..=colour::MAX_COLOUR).contains(&(self.colour as i64)) {
+            errors.push(BuildError::unspanned(format!(
+                "Your colour value (#{}) is outside of the range #000000 to #FFFFFF.",
+                hexadecimal::hex_to_str(self.colour as i64)
+            )));
+        }
+
+        // Check title and description
+        if let Some(title) = &self.title {
+            check_length(&mut errors, title, "title", limits.title, limits);
+        }
+
+        if let Some(description) = &self.description {
+            check_length(
+                &mut errors,
+                description,
+                "description",
+                limits.description,
+                limits,
+            );
+        }
+
+        // Check urls
+        for (url, field) in [
+            (&self.url, "Embed URL"),
+            (&self.image_url, "Image URL"),
+            (&self.thumbnail_url, "Thumbnail URL"),
+        ] {
+            check_url(&mut errors, url, field);
+        }
+
+        // Check fields
+        if self.fields.len() > limits.fields {
+            errors.push(BuildError::unspanned(format!(
+                "An embed can only have a maximum of {} fields.",
+                limits.fields
+            )));
+        }
+
+        for field in &self.fields {
+            if let Err(mut field_errors) = field.validate(limits) {
+                errors.append(&mut field_errors);
+            }
+        }
+
+        // Check footer
+        if let Some(footer) = &self.footer {
+            if let Err(mut footer_errors) = footer.validate(limits) {
+                errors.append(&mut footer_errors);
+            }
+        }
+
+        // Check timestamp
+        if let Some(timestamp) = self.timestamp {
+            if let Err(error) = validation::timestamp(timestamp) {
+                errors.push(BuildError::unspanned(error));
+            }
+        }
+
+        // Check total characters
+        let total_chars = self.total_length(limits.unit);
+
+        if total_chars > limits.embed_total {
+            errors.push(BuildError::unspanned(format!(
+                "Your embed has {} characters in total. An embed cannot exceed {} total characters.",
+                total_chars, limits.embed_total
+            )));
+        }
+
+        // Return errors
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// Get the total number of characters (code points) in this embed.
     pub fn total_chars(&self) -> usize {
+        self.total_length(LengthUnit::CodePoints)
+    }
+
+    /// Get the total length of the text in this embed, measured in a given unit.
+    pub fn total_length(&self, unit: LengthUnit) -> usize {
         // Initialise total chars
         let mut total_chars: usize = 0;
 
         // Add author name chars
         if let Some(author) = self.author.as_ref() {
-            total_chars += author.name.chars().count();
+            total_chars += length::measure(&author.name, unit);
         }
 
         // Add title chars
         if let Some(title) = self.title.as_ref() {
-            total_chars += title.chars().count();
+            total_chars += length::measure(title, unit);
         }
 
         // Add description chars
         if let Some(description) = self.description.as_ref() {
-            total_chars += description.chars().count();
+            total_chars += length::measure(description, unit);
         }
 
         // Add field chars
         for field in &self.fields {
-            total_chars += field.name.chars().count();
-            total_chars += field.value.chars().count();
+            total_chars += length::measure(&field.name, unit);
+            total_chars += length::measure(&field.value, unit);
         }
 
         // Add footer text chars
         if let Some(footer) = self.footer.as_ref() {
-            total_chars += footer.text.chars().count();
+            total_chars += length::measure(&footer.text, unit);
         }
 
         // Return total chars
@@ -250,6 +516,26 @@ impl From<StoredEmbedAuthor> for CreateEmbedAuthor {
     }
 }
 
+impl From<EmbedAuthor> for StoredEmbedAuthor {
+    fn from(value: EmbedAuthor) -> Self {
+        Self {
+            name: value.name,
+            url: value.url,
+            icon_url: value.icon_url,
+        }
+    }
+}
+
+impl From<EmbedAuthorPayload> for StoredEmbedAuthor {
+    fn from(value: EmbedAuthorPayload) -> Self {
+        Self {
+            name: value.name,
+            url: value.url,
+            icon_url: value.icon_url,
+        }
+    }
+}
+
 impl StoredEmbedAuthor {
     /// Create a new embed author using a given name.
     pub fn new(name: impl Into<String>) -> Self {
@@ -277,6 +563,37 @@ impl StoredEmbedAuthor {
         self.icon_url = Some(icon_url.into());
         self
     }
+
+    /// Check the embed author against the given limits, returning every violation found.
+    pub fn validate(&self, limits: &Limits) -> Result<(), Vec<BuildError>> {
+        // Create errors vector
+        let mut errors = Vec::new();
+
+        // Check if the author is empty
+        if self.name.is_empty() && self.url.is_none() && self.icon_url.is_none() {
+            errors.push(BuildError::unspanned(
+                "An embed cannot have an empty author.",
+            ));
+        }
+
+        // Check name and urls
+        check_length(
+            &mut errors,
+            &self.name,
+            "author name",
+            limits.author_name,
+            limits,
+        );
+        check_url(&mut errors, &self.url, "Embed Author URL");
+        check_url(&mut errors, &self.icon_url, "Embed Author Icon URL");
+
+        // Return errors
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -289,6 +606,24 @@ pub struct StoredEmbedFooter {
     pub icon_url: Option<String>,
 }
 
+impl From<EmbedFooter> for StoredEmbedFooter {
+    fn from(value: EmbedFooter) -> Self {
+        Self {
+            text: value.text,
+            icon_url: value.icon_url,
+        }
+    }
+}
+
+impl From<EmbedFooterPayload> for StoredEmbedFooter {
+    fn from(value: EmbedFooterPayload) -> Self {
+        Self {
+            text: value.text,
+            icon_url: value.icon_url,
+        }
+    }
+}
+
 impl StoredEmbedFooter {
     /// Create a new embed footer using a given piece of text.
     pub fn new(text: impl Into<String>) -> Self {
@@ -309,6 +644,36 @@ impl StoredEmbedFooter {
         self.icon_url = Some(icon_url.into());
         self
     }
+
+    /// Check the embed footer against the given limits, returning every violation found.
+    pub fn validate(&self, limits: &Limits) -> Result<(), Vec<BuildError>> {
+        // Create errors vector
+        let mut errors = Vec::new();
+
+        // Check if the footer is empty
+        if self.text.is_empty() && self.icon_url.is_none() {
+            errors.push(BuildError::unspanned(
+                "An embed cannot have an empty footer.",
+            ));
+        }
+
+        // Check text and icon url
+        check_length(
+            &mut errors,
+            &self.text,
+            "footer text",
+            limits.footer_text,
+            limits,
+        );
+        check_url(&mut errors, &self.icon_url, "Embed Footer Icon URL");
+
+        // Return errors
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
 }
 
 impl From<StoredEmbedFooter> for CreateEmbedFooter {
@@ -339,6 +704,32 @@ pub struct StoredEmbedField {
     pub inline: bool,
 }
 
+impl From<EmbedField> for StoredEmbedField {
+    fn from(value: EmbedField) -> Self {
+        Self {
+            name: value.name,
+            value: value.value,
+            inline: value.inline,
+        }
+    }
+}
+
+impl From<EmbedFieldPayload> for StoredEmbedField {
+    fn from(value: EmbedFieldPayload) -> Self {
+        Self {
+            name: value.name,
+            value: value.value,
+            inline: value.inline,
+        }
+    }
+}
+
+impl Default for StoredEmbedField {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl StoredEmbedField {
     /// Create a new, blank embed field.
     pub fn new() -> Self {
@@ -366,4 +757,107 @@ impl StoredEmbedField {
         self.inline = inline;
         self
     }
+
+    /// Check the embed field against the given limits, returning every violation found.
+    pub fn validate(&self, limits: &Limits) -> Result<(), Vec<BuildError>> {
+        // Create errors vector
+        let mut errors = Vec::new();
+
+        // Check if the field has a name and value
+        if self.name.is_empty() || self.value.is_empty() {
+            errors.push(BuildError::unspanned(
+                "All of your embed fields must have both a name and a value.",
+            ));
+        }
+
+        // Check name and value
+        check_length(
+            &mut errors,
+            &self.name,
+            "field name",
+            limits.field_name,
+            limits,
+        );
+        check_length(
+            &mut errors,
+            &self.value,
+            "field value",
+            limits.field_value,
+            limits,
+        );
+
+        // Return errors
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+}
+
+/// Add an error if some text is above its limit.
+fn check_length(errors: &mut Vec<BuildError>, text: &str, name: &str, max: usize, limits: &Limits) {
+    // Get text length
+    let text_length = limits.length(text);
+
+    // Add length error
+    if text_length > max {
+        errors.push(BuildError::unspanned(format!(
+            "The length of your {} ({}) is above the maximum of {} characters.",
+            name, text_length, max
+        )));
+    }
+}
+
+/// Add an error if a url is set but isn't valid.
+fn check_url(errors: &mut Vec<BuildError>, url: &Option<String>, field: &str) {
+    if let Some(Err(error)) = url.as_ref().map(|url| validation::url(url, field)) {
+        errors.push(BuildError::unspanned(error));
+    }
+}
+
+/// Convert an embed's unix timestamp into a date, checking that it can be represented as one.
+fn to_timestamp(timestamp: i64) -> Result<Timestamp, BuildError> {
+    validation::timestamp(timestamp).map_err(BuildError::unspanned)?;
+    Timestamp::from_unix_timestamp(timestamp)
+        .map_err(|error| BuildError::unspanned(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::StoredMessage;
+
+    #[test]
+    fn rejects_out_of_range_colours_when_storing() {
+        let embed = |colour: u32| -> Embed {
+            serde_json::from_value(serde_json::json!({ "title": "t", "color": colour })).unwrap()
+        };
+        assert_eq!(
+            StoredEmbed::try_from(embed(0xFFFFFF)).unwrap().colour,
+            0xFFFFFF
+        );
+        assert!(StoredEmbed::try_from(embed(0x1000000)).is_err());
+        assert!(StoredEmbed::try_from(embed(u32::MAX)).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_timestamps_when_exporting() {
+        let embed = || StoredEmbed::new().title("t").timestamp(i64::MAX);
+        assert!(EmbedPayload::try_from(&embed()).is_err());
+        assert!(CreateEmbed::try_from(embed()).is_err());
+
+        let mut message = StoredMessage::new();
+        message.embeds.push(embed());
+        assert!(message.to_discord_json().is_err());
+    }
+
+    #[test]
+    fn exports_valid_timestamps() {
+        let payload = EmbedPayload::try_from(&StoredEmbed::new().timestamp(13_454_321)).unwrap();
+        assert_eq!(
+            payload.timestamp.map(|time| time.unix_timestamp()),
+            Some(13_454_321)
+        );
+    }
 }
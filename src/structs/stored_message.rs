@@ -0,0 +1,861 @@
+use crate::enums::Overflow;
+use crate::helpers::{length, split, validation};
+use crate::structs::{
+    AllowedMentionsPayload, AttachmentPayload, BuildError, BuildWarning, EmbedPayload,
+    InteractionContext, Limits, MessagePayload, MessageSettings, PollPayload, StoredActionRow,
+    StoredAllowedMentions, StoredAttachment, StoredEmbed, StoredPoll, StoredWebhook,
+};
+use serde::{Deserialize, Serialize};
+use serenity::all::{
+    ActionRow, CreateActionRow, CreateAttachment, CreateEmbed, CreateInteractionResponseMessage,
+    CreateMessage, EditInteractionResponse, EditMessage, ExecuteWebhook,
+};
+use std::collections::HashSet;
+
+/// The file name used for content that is moved into an attachment.
+pub const OVERFLOW_FILENAME: &str = "message.txt";
+
+#[derive(Serialize, Deserialize, Debug, Default)]
+/// A stored message that can be sent by the bot.
+pub struct StoredMessage {
+    /// The message's content.
+    pub content: String,
+
+    /// The message's embeds.
+    pub embeds: Vec<StoredEmbed>,
+
+    /// The message's attachments.
+    pub attachments: Vec<StoredAttachment>,
+
+    /// The message's action rows of buttons and select menus.
+    pub components: Vec<StoredActionRow>,
+
+    /// The message's poll. Polls can't be edited, so they are only sent when creating a message
+    /// or an interaction response.
+    ///
+    /// Converting a message with a poll into an edit or an [`ExecuteWebhook`] fails, as serenity's
+    /// webhook builder has no way to send a poll. Send the message's JSON payload to the webhook
+    /// instead to keep the poll.
+    pub poll: Option<StoredPoll>,
+
+    /// The settings the message is sent with, if any are set.
+    pub settings: Option<MessageSettings>,
+
+    /// The kinds of mentions that notify the people they mention. When this isn't set, the
+    /// default of notifying users and roles but not `@everyone` is used.
+    pub allowed_mentions: Option<StoredAllowedMentions>,
+
+    /// The identity the message is sent with through a webhook. This is only used when the
+    /// message is sent by executing a webhook.
+    pub webhook: Option<StoredWebhook>,
+}
+
+impl TryFrom<MessagePayload> for StoredMessage {
+    type Error = BuildError;
+
+    fn try_from(value: MessagePayload) -> Result<Self, Self::Error> {
+        // Check for attachments, which can't be imported
+        if !value.attachments.is_empty() {
+            return Err(BuildError::unspanned(
+                "Messages with attachments can't be imported.",
+            ));
+        }
+
+        // Create message
+        let mut message = Self::new();
+
+        // Add content
+        if let Some(content) = value.content {
+            message.push_content(content);
+        }
+
+        // Add embeds
+        for embed in value.embeds {
+            message.push_embed(embed.try_into()?);
+        }
+
+        // Add action rows
+        for row in value.components {
+            let row: ActionRow = serde_json::from_value(row).map_err(|error| {
+                BuildError::unspanned(format!("Invalid message component: {}.", error))
+            })?;
+            message.push_action_row(row.try_into()?);
+        }
+
+        // Add poll
+        message.poll = value.poll.map(StoredPoll::try_from).transpose()?;
+
+        // Add settings
+        let flags = value.flags.unwrap_or(0);
+        let tts = value.tts.unwrap_or(false);
+
+        if flags != 0 || tts {
+            message.settings = Some(MessageSettings::from_flags(flags, tts));
+        }
+
+        // Add allowed mentions
+        message.allowed_mentions = value
+            .allowed_mentions
+            .map(StoredAllowedMentions::try_from)
+            .transpose()?;
+
+        // Add webhook identity
+        let webhook = StoredWebhook {
+            username: value.username,
+            avatar_url: value.avatar_url,
+            thread_name: value.thread_name,
+        };
+
+        if webhook != StoredWebhook::default() {
+            message.webhook = Some(webhook);
+        }
+
+        // Return message
+        Ok(message)
+    }
+}
+
+impl TryFrom<&StoredMessage> for MessagePayload {
+    type Error = BuildError;
+
+    fn try_from(value: &StoredMessage) -> Result<Self, Self::Error> {
+        Ok(Self {
+            content: (!value.content.is_empty()).then(|| value.content.clone()),
+            embeds: value
+                .embeds
+                .iter()
+                .map(EmbedPayload::try_from)
+                .collect::<Result<_, _>>()?,
+            attachments: value
+                .attachments
+                .iter()
+                .enumerate()
+                .map(|(index, attachment)| AttachmentPayload {
+                    id: index.into(),
+                    ..attachment.into()
+                })
+                .collect(),
+            components: value
+                .components
+                .iter()
+                .map(|row| serde_json::to_value(CreateActionRow::from(row.clone())).unwrap())
+                .collect(),
+            poll: value.poll.as_ref().map(PollPayload::from),
+            tts: value.settings.filter(|settings| settings.tts).map(|_| true),
+            flags: value
+                .settings
+                .map(|settings| settings.flags())
+                .filter(|flags| *flags != 0),
+            allowed_mentions: Some(AllowedMentionsPayload::from(
+                &value.allowed_mentions.unwrap_or_default(),
+            )),
+            username: value
+                .webhook
+                .as_ref()
+                .and_then(|webhook| webhook.username.clone()),
+            avatar_url: value
+                .webhook
+                .as_ref()
+                .and_then(|webhook| webhook.avatar_url.clone()),
+            thread_name: value
+                .webhook
+                .as_ref()
+                .and_then(|webhook| webhook.thread_name.clone()),
+        })
+    }
+}
+
+impl StoredMessage {
+    /// Create a new, empty message.
+    pub fn new() -> Self {
+        Self {
+            content: String::new(),
+            embeds: Vec::new(),
+            attachments: Vec::new(),
+            components: Vec::new(),
+            poll: None,
+            settings: None,
+            allowed_mentions: None,
+            webhook: None,
+        }
+    }
+
+    /// Export the message in Discord's JSON format, ready to send to the API or a webhook.
+    pub fn to_discord_json(&self) -> Result<String, BuildError> {
+        Ok(serde_json::to_string(&MessagePayload::try_from(self)?).unwrap())
+    }
+
+    /// Push to the message's content.
+    pub fn push_content(&mut self, content: impl Into<String>) {
+        self.content.push_str(&content.into());
+    }
+
+    /// Add an embed to the message.
+    pub fn push_embed(&mut self, embed: StoredEmbed) {
+        self.embeds.push(embed);
+    }
+
+    /// Add an attachment to the message.
+    pub fn push_attachment(&mut self, attachment: StoredAttachment) {
+        self.attachments.push(attachment);
+    }
+
+    /// Add an action row to the message.
+    pub fn push_action_row(&mut self, row: StoredActionRow) {
+        self.components.push(row);
+    }
+
+    /// Check whether the message has nothing to send.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+            && self.embeds.is_empty()
+            && self.attachments.is_empty()
+            && self.components.is_empty()
+            && self.poll.is_none()
+    }
+
+    /// Replace the interaction context variables in the message's content and embed text.
+    pub fn substitute(&mut self, context: &InteractionContext) {
+        // Substitute content
+        self.content = context.substitute(&self.content);
+
+        // Substitute embed text
+        for embed in &mut self.embeds {
+            for text in [&mut embed.title, &mut embed.description]
+                .into_iter()
+                .flatten()
+            {
+                *text = context.substitute(text);
+            }
+
+            if let Some(author) = &mut embed.author {
+                author.name = context.substitute(&author.name);
+            }
+
+            for field in &mut embed.fields {
+                field.name = context.substitute(&field.name);
+                field.value = context.substitute(&field.value);
+            }
+
+            if let Some(footer) = &mut embed.footer {
+                footer.text = context.substitute(&footer.text);
+            }
+        }
+    }
+
+    /// Check that every `attachment://` URL in the message's embeds refers to one of its
+    /// attachments, and point them at the name each attachment is uploaded with.
+    pub fn link_attachments(&mut self) -> Result<(), BuildError> {
+        for embed in &mut self.embeds {
+            for url in attachment_urls_mut(embed) {
+                // Skip URLs that aren't attachment references
+                let Some(name) = validation::attachment_name(url) else {
+                    continue;
+                };
+
+                // Find attachment
+                let attachment = self
+                    .attachments
+                    .iter()
+                    .find(|attachment| attachment.is_named(name))
+                    .ok_or_else(|| missing_attachment_error(url))?;
+
+                // Use the uploaded file name
+                *url = format!(
+                    "{}{}",
+                    validation::ATTACHMENT_SCHEME,
+                    attachment.upload_filename()
+                );
+            }
+        }
+
+        // Return success
+        Ok(())
+    }
+
+    /// Handle content that is longer than the content limit, returning the messages to send in
+    /// place of this one.
+    pub fn overflow(
+        mut self,
+        overflow: Overflow,
+        limits: &Limits,
+        warnings: &mut Vec<BuildWarning>,
+    ) -> Vec<StoredMessage> {
+        // Return message unchanged if its content fits
+        let content_chars = limits.length(&self.content);
+
+        if content_chars <= limits.content {
+            return vec![self];
+        }
+
+        match overflow {
+            Overflow::Error => vec![self],
+            Overflow::Split => {
+                // Split content into chunks
+                let chunks = split::split(&self.content, limits.content, limits.unit);
+                warnings.push(BuildWarning::unspanned(format!(
+                    "The length of your message content ({}) is above the maximum of {} characters. It was split into {} messages.",
+                    content_chars,
+                    limits.content,
+                    chunks.len()
+                )));
+
+                // Create a message for each chunk, keeping embeds and attachments on the last one
+                let count = chunks.len();
+                chunks
+                    .into_iter()
+                    .enumerate()
+                    .map(|(index, chunk)| {
+                        let mut message = if index + 1 == count {
+                            std::mem::take(&mut self)
+                        } else {
+                            StoredMessage {
+                                settings: self.settings,
+                                allowed_mentions: self.allowed_mentions,
+                                webhook: self.webhook.clone(),
+                                ..StoredMessage::new()
+                            }
+                        };
+                        message.content = chunk;
+                        message
+                    })
+                    .collect()
+            }
+            Overflow::Attachment => {
+                // Keep the first chunk as content, and move the rest into an attachment
+                let (chunk, rest) = split::split_first(&self.content, limits.content, limits.unit);
+                self.content = chunk;
+                self.push_attachment(StoredAttachment::new(OVERFLOW_FILENAME, rest));
+                warnings.push(BuildWarning::unspanned(format!(
+                    "The length of your message content ({}) is above the maximum of {} characters. The rest of it was moved into {}.",
+                    content_chars, limits.content, OVERFLOW_FILENAME
+                )));
+                vec![self]
+            }
+        }
+    }
+
+    /// Shorten content and remove embeds until the message fits within the given limits, adding a
+    /// warning for each change.
+    pub fn fit(&mut self, limits: &Limits, warnings: &mut Vec<BuildWarning>) {
+        // Shorten content if it exceeds the content limit
+        let content_chars = limits.length(&self.content);
+
+        if content_chars > limits.content {
+            self.content = length::truncate(&self.content, limits.content, limits.unit);
+            warnings.push(BuildWarning::unspanned(format!(
+                "The length of your message content ({}) is above the maximum of {} characters. It was shortened to fit.",
+                content_chars, limits.content
+            )));
+        }
+
+        // Remove embeds over the embed limit
+        if self.embeds.len() > limits.embeds {
+            let removed = self.embeds.len() - limits.embeds;
+            self.embeds.truncate(limits.embeds);
+            warnings.push(BuildWarning::unspanned(format!(
+                "A message can only have a maximum of {} embeds. {} embeds were removed.",
+                limits.embeds, removed
+            )));
+        }
+
+        // Remove embeds from the end until the total characters fit
+        let total_length = |embeds: &[StoredEmbed]| -> usize {
+            embeds
+                .iter()
+                .map(|embed| embed.total_length(limits.unit))
+                .sum()
+        };
+        let total_chars = total_length(&self.embeds);
+
+        if total_chars > limits.message_embed_total {
+            let mut removed = 0;
+            while total_length(&self.embeds) > limits.message_embed_total {
+                self.embeds.pop();
+                removed += 1;
+            }
+            warnings.push(BuildWarning::unspanned(format!(
+                "Your embeds have {} characters in total. The embeds in a message cannot exceed {} total characters. {} embeds were removed.",
+                total_chars, limits.message_embed_total, removed
+            )));
+        }
+    }
+
+    /// Check that the message can be sent within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check if content exceeds the content limit
+        let content_chars = limits.length(&self.content);
+
+        if content_chars > limits.content {
+            // Return content length error
+            return Err(BuildError::unspanned(format!(
+                "The length of your message content ({}) is above the maximum of {} characters.",
+                content_chars, limits.content
+            )));
+        }
+
+        // Check if there are too many embeds
+        if self.embeds.len() > limits.embeds {
+            // Return too many embeds error
+            return Err(BuildError::unspanned(format!(
+                "Your message has {} embeds. A message can only have a maximum of {} embeds.",
+                self.embeds.len(),
+                limits.embeds
+            )));
+        }
+
+        // Check if there are too many attachments
+        if self.attachments.len() > limits.attachments {
+            // Return too many attachments error
+            return Err(BuildError::unspanned(format!(
+                "Your message has {} attachments. A message can only have a maximum of {} attachments.",
+                self.attachments.len(),
+                limits.attachments
+            )));
+        }
+
+        // Check each attachment
+        for attachment in &self.attachments {
+            validation::filename(&attachment.filename).map_err(BuildError::unspanned)?;
+
+            if attachment.data.len() > limits.attachment_size {
+                return Err(BuildError::unspanned(format!(
+                    "The attachment {} ({} bytes) is above the maximum attachment size of {} bytes.",
+                    attachment.filename,
+                    attachment.data.len(),
+                    limits.attachment_size
+                )));
+            }
+
+            if let Some(description) = &attachment.description {
+                let description_chars = limits.length(description);
+
+                if description_chars > limits.attachment_description {
+                    return Err(BuildError::unspanned(format!(
+                        "The length of the description for {} ({}) is above the maximum of {} characters.",
+                        attachment.filename, description_chars, limits.attachment_description
+                    )));
+                }
+            }
+        }
+
+        // Check if there are too many action rows
+        if self.components.len() > limits.action_rows {
+            // Return too many action rows error
+            return Err(BuildError::unspanned(format!(
+                "Your message has {} action rows. A message can only have a maximum of {} action rows.",
+                self.components.len(),
+                limits.action_rows
+            )));
+        }
+
+        // Check each action row, and that custom ids are unique
+        let mut custom_ids = HashSet::new();
+
+        for row in &self.components {
+            row.validate(limits)?;
+
+            for custom_id in row.custom_ids() {
+                if !custom_ids.insert(custom_id) {
+                    return Err(BuildError::unspanned(format!(
+                        "The custom id {} is used by more than one component in your message.",
+                        custom_id
+                    )));
+                }
+            }
+        }
+
+        // Check poll and webhook identity
+        if let Some(poll) = &self.poll {
+            poll.validate(limits)?;
+        }
+
+        if let Some(webhook) = &self.webhook {
+            webhook.validate(limits)?;
+        }
+
+        // Check each embed
+        for embed in &self.embeds {
+            // Check that attachment references resolve
+            for url in attachment_urls(embed) {
+                if let Some(name) = validation::attachment_name(url) {
+                    if !self
+                        .attachments
+                        .iter()
+                        .any(|attachment| attachment.is_named(name))
+                    {
+                        return Err(missing_attachment_error(url));
+                    }
+                }
+            }
+
+            embed
+                .validate(limits)
+                .map_err(|errors| errors.into_iter().next().unwrap())?;
+        }
+
+        // Get the total number of characters across all embeds
+        let total_chars: usize = self
+            .embeds
+            .iter()
+            .map(|embed| embed.total_length(limits.unit))
+            .sum();
+
+        // Check if embed characters don't exceed the message limit
+        if total_chars > limits.message_embed_total {
+            // Return total chars error
+            return Err(BuildError::unspanned(format!(
+                "Your embeds have {} characters in total. The embeds in a message cannot exceed {} total characters.",
+                total_chars, limits.message_embed_total
+            )));
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+/// Get the URLs in an embed that can refer to attachments.
+fn attachment_urls(embed: &StoredEmbed) -> impl Iterator<Item = &String> {
+    [
+        embed.image_url.as_ref(),
+        embed.thumbnail_url.as_ref(),
+        embed
+            .author
+            .as_ref()
+            .and_then(|author| author.icon_url.as_ref()),
+        embed
+            .footer
+            .as_ref()
+            .and_then(|footer| footer.icon_url.as_ref()),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Get mutable references to the URLs in an embed that can refer to attachments.
+fn attachment_urls_mut(embed: &mut StoredEmbed) -> impl Iterator<Item = &mut String> {
+    [
+        embed.image_url.as_mut(),
+        embed.thumbnail_url.as_mut(),
+        embed
+            .author
+            .as_mut()
+            .and_then(|author| author.icon_url.as_mut()),
+        embed
+            .footer
+            .as_mut()
+            .and_then(|footer| footer.icon_url.as_mut()),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// Create the error for an attachment URL that doesn't refer to an attachment in the message.
+fn missing_attachment_error(url: &str) -> BuildError {
+    BuildError::unspanned(format!(
+        "Your embed refers to {}, but the message has no attachment with that file name.",
+        url
+    ))
+}
+
+impl TryFrom<StoredMessage> for CreateMessage {
+    type Error = BuildError;
+
+    fn try_from(value: StoredMessage) -> Result<Self, Self::Error> {
+        // Check that the message isn't ephemeral, as only interaction responses can be
+        if value.settings.is_some_and(|settings| settings.ephemeral) {
+            return Err(BuildError::unspanned(
+                "Only interaction responses can be ephemeral.",
+            ));
+        }
+
+        // Create message with embeds, attachments and components
+        let mut message = CreateMessage::new()
+            .embeds(
+                value
+                    .embeds
+                    .into_iter()
+                    .map(CreateEmbed::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+            .add_files(value.attachments.into_iter().map(CreateAttachment::from))
+            .components(
+                value
+                    .components
+                    .into_iter()
+                    .map(CreateActionRow::from)
+                    .collect(),
+            );
+
+        // Add content if there is any
+        if !value.content.is_empty() {
+            message = message.content(value.content);
+        }
+
+        // Add poll if there is one
+        if let Some(poll) = value.poll {
+            message = message.poll(poll.into());
+        }
+
+        // Add settings and allowed mentions
+        let settings = value.settings.unwrap_or_default();
+        message = message
+            .tts(settings.tts)
+            .allowed_mentions(value.allowed_mentions.unwrap_or_default().into());
+
+        if !settings.message_flags().is_empty() {
+            message = message.flags(settings.message_flags());
+        }
+
+        // Return message
+        Ok(message)
+    }
+}
+
+impl TryFrom<StoredMessage> for EditMessage {
+    type Error = BuildError;
+
+    fn try_from(value: StoredMessage) -> Result<Self, Self::Error> {
+        // Check that there is no poll, as polls can't be edited
+        if value.poll.is_some() {
+            return Err(BuildError::unspanned(
+                "A poll can't be added to a message by editing it.",
+            ));
+        }
+
+        // Replace content, embeds and components, so that old ones are removed
+        let mut message = EditMessage::new()
+            .embeds(
+                value
+                    .embeds
+                    .into_iter()
+                    .map(CreateEmbed::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+            .content(value.content)
+            .components(
+                value
+                    .components
+                    .into_iter()
+                    .map(CreateActionRow::from)
+                    .collect(),
+            );
+
+        // Add attachments
+        for attachment in value.attachments {
+            message = message.new_attachment(attachment.into());
+        }
+
+        // Add embed suppression and allowed mentions
+        if let Some(settings) = value.settings {
+            message = message.suppress_embeds(settings.suppress_embeds);
+        }
+
+        message = message.allowed_mentions(value.allowed_mentions.unwrap_or_default().into());
+
+        // Return message
+        Ok(message)
+    }
+}
+
+impl TryFrom<StoredMessage> for CreateInteractionResponseMessage {
+    type Error = BuildError;
+
+    fn try_from(value: StoredMessage) -> Result<Self, Self::Error> {
+        // Create message with embeds, attachments and components
+        let mut message = CreateInteractionResponseMessage::new()
+            .embeds(
+                value
+                    .embeds
+                    .into_iter()
+                    .map(CreateEmbed::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+            .add_files(value.attachments.into_iter().map(CreateAttachment::from))
+            .components(
+                value
+                    .components
+                    .into_iter()
+                    .map(CreateActionRow::from)
+                    .collect(),
+            );
+
+        // Add content if there is any
+        if !value.content.is_empty() {
+            message = message.content(value.content);
+        }
+
+        // Add poll if there is one
+        if let Some(poll) = value.poll {
+            message = message.poll(poll.into());
+        }
+
+        // Add settings and allowed mentions
+        let settings = value.settings.unwrap_or_default();
+        message = message
+            .tts(settings.tts)
+            .allowed_mentions(value.allowed_mentions.unwrap_or_default().into());
+
+        if !settings.interaction_flags().is_empty() {
+            message = message.flags(settings.interaction_flags());
+        }
+
+        // Return message
+        Ok(message)
+    }
+}
+
+impl TryFrom<StoredMessage> for EditInteractionResponse {
+    type Error = BuildError;
+
+    fn try_from(value: StoredMessage) -> Result<Self, Self::Error> {
+        // Check that there is no poll, as polls can't be edited
+        if value.poll.is_some() {
+            return Err(BuildError::unspanned(
+                "A poll can't be added to a message by editing it.",
+            ));
+        }
+
+        // Replace content, embeds and components, so that old ones are removed
+        let mut message = EditInteractionResponse::new()
+            .embeds(
+                value
+                    .embeds
+                    .into_iter()
+                    .map(CreateEmbed::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+            .content(value.content)
+            .components(
+                value
+                    .components
+                    .into_iter()
+                    .map(CreateActionRow::from)
+                    .collect(),
+            );
+
+        // Add attachments
+        for attachment in value.attachments {
+            message = message.new_attachment(attachment.into());
+        }
+
+        // Add allowed mentions
+        message = message.allowed_mentions(value.allowed_mentions.unwrap_or_default().into());
+
+        // Return message
+        Ok(message)
+    }
+}
+
+impl TryFrom<StoredMessage> for ExecuteWebhook {
+    type Error = BuildError;
+
+    fn try_from(value: StoredMessage) -> Result<Self, Self::Error> {
+        // Check that there is no poll, as serenity can't send one with a webhook
+        if value.poll.is_some() {
+            return Err(BuildError::unspanned(
+                "A poll can't be sent with a webhook. Send the message's JSON payload instead.",
+            ));
+        }
+
+        // Check that the message isn't ephemeral, as only interaction responses can be
+        if value.settings.is_some_and(|settings| settings.ephemeral) {
+            return Err(BuildError::unspanned(
+                "Only interaction responses can be ephemeral.",
+            ));
+        }
+
+        // Create webhook message with embeds, attachments and components
+        let mut message = ExecuteWebhook::new()
+            .embeds(
+                value
+                    .embeds
+                    .into_iter()
+                    .map(CreateEmbed::try_from)
+                    .collect::<Result<_, _>>()?,
+            )
+            .add_files(value.attachments.into_iter().map(CreateAttachment::from))
+            .components(
+                value
+                    .components
+                    .into_iter()
+                    .map(CreateActionRow::from)
+                    .collect(),
+            );
+
+        // Add content if there is any
+        if !value.content.is_empty() {
+            message = message.content(value.content);
+        }
+
+        // Add settings and allowed mentions
+        let settings = value.settings.unwrap_or_default();
+        message = message
+            .tts(settings.tts)
+            .allowed_mentions(value.allowed_mentions.unwrap_or_default().into());
+
+        if !settings.message_flags().is_empty() {
+            message = message.flags(settings.message_flags());
+        }
+
+        // Add webhook identity
+        if let Some(webhook) = value.webhook {
+            if let Some(username) = webhook.username {
+                message = message.username(username);
+            }
+
+            if let Some(avatar_url) = webhook.avatar_url {
+                message = message.avatar_url(avatar_url);
+            }
+
+            if let Some(thread_name) = webhook.thread_name {
+                message = message.thread_name(thread_name);
+            }
+        }
+
+        // Return message
+        Ok(message)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structs::{MessageSettings, StoredPollAnswer};
+
+    fn poll_message() -> StoredMessage {
+        let mut poll = StoredPoll::new("Question?");
+        poll.answers.push(StoredPollAnswer::new("Answer"));
+
+        let mut message = StoredMessage::new();
+        message.poll = Some(poll);
+        message
+    }
+
+    fn ephemeral_message() -> StoredMessage {
+        let mut message = StoredMessage::new();
+        message.content = "Only you can see this".to_string();
+        message.settings = Some(MessageSettings {
+            ephemeral: true,
+            ..MessageSettings::new()
+        });
+        message
+    }
+
+    #[test]
+    fn rejects_polls_that_cant_be_sent() {
+        assert!(CreateMessage::try_from(poll_message()).is_ok());
+        assert!(CreateInteractionResponseMessage::try_from(poll_message()).is_ok());
+        assert!(ExecuteWebhook::try_from(poll_message()).is_err());
+        assert!(EditMessage::try_from(poll_message()).is_err());
+        assert!(EditInteractionResponse::try_from(poll_message()).is_err());
+    }
+
+    #[test]
+    fn rejects_ephemeral_messages_outside_of_interactions() {
+        assert!(CreateInteractionResponseMessage::try_from(ephemeral_message()).is_ok());
+        assert!(CreateMessage::try_from(ephemeral_message()).is_err());
+        assert!(ExecuteWebhook::try_from(ephemeral_message()).is_err());
+    }
+}
@@ -0,0 +1,260 @@
+use crate::structs::stored_component::{check_emoji, check_length};
+use crate::structs::{
+    BuildError, Limits, PollAnswerPayload, PollEmojiPayload, PollMediaPayload, PollPayload,
+};
+use serde::{Deserialize, Serialize};
+use serenity::all::{CreatePoll, CreatePollAnswer, PollMediaEmoji, ReactionType};
+use serenity::builder::create_poll::Ready;
+use std::time::Duration;
+
+/// The number of hours a poll is open for when no duration is given.
+pub const DEFAULT_POLL_DURATION: u32 = 24;
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored message poll.
+pub struct StoredPoll {
+    /// The poll's question.
+    pub question: String,
+
+    /// The poll's answers.
+    pub answers: Vec<StoredPollAnswer>,
+
+    /// How long the poll is open for, in hours.
+    pub duration: u32,
+
+    /// Whether more than one answer can be chosen.
+    pub multi_select: bool,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
+/// A stored poll answer.
+pub struct StoredPollAnswer {
+    /// The answer's text.
+    pub label: String,
+
+    /// The answer's emoji, either a unicode emoji or a custom emoji such as `<:name:id>`.
+    pub emoji: Option<String>,
+}
+
+impl StoredPoll {
+    /// Create a new poll with a given question.
+    pub fn new(question: impl Into<String>) -> Self {
+        Self {
+            question: question.into(),
+            answers: Vec::new(),
+            duration: DEFAULT_POLL_DURATION,
+            multi_select: false,
+        }
+    }
+
+    /// Check that the poll is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check question
+        if self.question.is_empty() {
+            return Err(BuildError::unspanned("A poll needs a #question."));
+        }
+
+        check_length(
+            &self.question,
+            "poll question",
+            limits.poll_question,
+            limits,
+        )?;
+
+        // Check answers
+        if self.answers.is_empty() {
+            return Err(BuildError::unspanned("A poll needs at least one #answer."));
+        }
+
+        if self.answers.len() > limits.poll_answers {
+            return Err(BuildError::unspanned(format!(
+                "Your poll has {} answers. A poll can only have a maximum of {} answers.",
+                self.answers.len(),
+                limits.poll_answers
+            )));
+        }
+
+        for answer in &self.answers {
+            answer.validate(limits)?;
+        }
+
+        // Check duration
+        if self.duration == 0 || self.duration > limits.poll_duration {
+            return Err(BuildError::unspanned(format!(
+                "Your poll's duration ({} hours) must be between 1 and {} hours.",
+                self.duration, limits.poll_duration
+            )));
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+impl StoredPollAnswer {
+    /// Create a new poll answer.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            emoji: None,
+        }
+    }
+
+    /// Check that the answer is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check label
+        if self.label.is_empty() {
+            return Err(BuildError::unspanned("A poll answer needs a #label."));
+        }
+
+        check_length(&self.label, "poll answer", limits.poll_answer_text, limits)?;
+
+        // Check emoji
+        if let Some(emoji) = &self.emoji {
+            check_emoji(emoji)?;
+        }
+
+        // Return success
+        Ok(())
+    }
+}
+
+impl From<StoredPoll> for CreatePoll<Ready> {
+    fn from(value: StoredPoll) -> Self {
+        // Create answers
+        let answers = value
+            .answers
+            .into_iter()
+            .map(|answer| {
+                let mut create = CreatePollAnswer::new().text(answer.label);
+
+                match answer
+                    .emoji
+                    .and_then(|emoji| ReactionType::try_from(emoji).ok())
+                {
+                    Some(ReactionType::Custom { id, .. }) => {
+                        create = create.emoji(PollMediaEmoji::Id(id));
+                    }
+                    Some(ReactionType::Unicode(name)) => {
+                        create = create.emoji(PollMediaEmoji::Name(name));
+                    }
+                    _ => {}
+                }
+
+                create
+            })
+            .collect();
+
+        // Create poll
+        let poll = CreatePoll::new()
+            .question(value.question)
+            .answers(answers)
+            .duration(Duration::from_secs(u64::from(value.duration) * 3600));
+
+        // Return poll, allowing more than one answer if needed
+        if value.multi_select {
+            poll.allow_multiselect()
+        } else {
+            poll
+        }
+    }
+}
+
+impl From<&StoredPoll> for PollPayload {
+    fn from(value: &StoredPoll) -> Self {
+        Self {
+            question: PollMediaPayload {
+                text: Some(value.question.clone()),
+                emoji: None,
+            },
+            answers: value
+                .answers
+                .iter()
+                .map(|answer| PollAnswerPayload {
+                    answer_id: None,
+                    poll_media: PollMediaPayload {
+                        text: Some(answer.label.clone()),
+                        emoji: answer.emoji.as_deref().and_then(emoji_payload),
+                    },
+                })
+                .collect(),
+            duration: Some(value.duration),
+            allow_multiselect: value.multi_select,
+        }
+    }
+}
+
+impl TryFrom<PollPayload> for StoredPoll {
+    type Error = BuildError;
+
+    fn try_from(value: PollPayload) -> Result<Self, Self::Error> {
+        // Create poll
+        let mut poll = StoredPoll::new(value.question.text.unwrap_or_default());
+        poll.duration = value.duration.unwrap_or(DEFAULT_POLL_DURATION);
+        poll.multi_select = value.allow_multiselect;
+
+        // Add answers
+        for answer in value.answers {
+            let media = answer.poll_media;
+            poll.answers.push(StoredPollAnswer {
+                label: media.text.unwrap_or_default(),
+                emoji: media.emoji.map(emoji_string).transpose()?,
+            });
+        }
+
+        // Return poll
+        Ok(poll)
+    }
+}
+
+/// Convert a unicode or custom emoji into a poll emoji.
+fn emoji_payload(emoji: &str) -> Option<PollEmojiPayload> {
+    match ReactionType::try_from(emoji).ok()? {
+        ReactionType::Custom { animated, id, name } => Some(PollEmojiPayload {
+            id: Some(id.to_string()),
+            name,
+            animated: Some(animated),
+        }),
+        ReactionType::Unicode(name) => Some(PollEmojiPayload {
+            name: Some(name),
+            ..Default::default()
+        }),
+        _ => None,
+    }
+}
+
+/// Convert a poll emoji into a unicode emoji or a custom emoji such as `<:name:id>`.
+fn emoji_string(emoji: PollEmojiPayload) -> Result<String, BuildError> {
+    match (emoji.id, emoji.name) {
+        (Some(id), name) => Ok(format!(
+            "<{}:{}:{}>",
+            if emoji.animated.unwrap_or(false) {
+                "a"
+            } else {
+                ""
+            },
+            name.unwrap_or_else(|| "emoji".to_string()),
+            id
+        )),
+        (None, Some(name)) => Ok(name),
+        (None, None) => Err(BuildError::unspanned(
+            "A poll answer's emoji needs an id or a name.",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sends_the_longest_poll_duration() {
+        let mut poll = StoredPoll::new("Question?");
+        poll.answers.push(StoredPollAnswer::new("Answer"));
+        poll.duration = Limits::discord().poll_duration;
+        assert!(poll.validate(&Limits::discord()).is_ok());
+
+        let json = serde_json::to_value(CreatePoll::<Ready>::from(poll)).unwrap();
+        assert_eq!(json["duration"], 768);
+    }
+}
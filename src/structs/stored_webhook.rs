@@ -0,0 +1,79 @@
+use crate::helpers::validation;
+use crate::structs::stored_component::check_length;
+use crate::structs::{BuildError, Limits};
+use serde::{Deserialize, Serialize};
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+/// The identity a message is sent with when it is sent through a webhook.
+pub struct StoredWebhook {
+    /// The username shown instead of the webhook's name.
+    pub username: Option<String>,
+
+    /// The URL of the avatar shown instead of the webhook's avatar.
+    pub avatar_url: Option<String>,
+
+    /// The name of the thread to create for the message, for webhooks in forum channels.
+    pub thread_name: Option<String>,
+}
+
+impl StoredWebhook {
+    /// Create a new webhook identity, which uses the webhook's own name and avatar.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the username.
+    pub fn username(mut self, username: impl Into<String>) -> Self {
+        self.username = Some(username.into());
+        self
+    }
+
+    /// Set the avatar URL.
+    pub fn avatar_url(mut self, avatar_url: impl Into<String>) -> Self {
+        self.avatar_url = Some(avatar_url.into());
+        self
+    }
+
+    /// Set the thread name.
+    pub fn thread_name(mut self, thread_name: impl Into<String>) -> Self {
+        self.thread_name = Some(thread_name.into());
+        self
+    }
+
+    /// Check that the webhook identity is within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        // Check username
+        if let Some(username) = &self.username {
+            validation::webhook_username(username).map_err(BuildError::unspanned)?;
+            check_length(
+                username,
+                "webhook username",
+                limits.webhook_username,
+                limits,
+            )?;
+        }
+
+        // Check avatar URL, which has to be a link since webhooks can't use attachments as avatars
+        if let Some(avatar_url) = &self.avatar_url {
+            if validation::attachment_name(avatar_url).is_some() {
+                return Err(BuildError::unspanned(
+                    "A webhook's avatar can't be an attachment. Use a link to an image instead.",
+                ));
+            }
+
+            validation::url(avatar_url, "webhook avatar").map_err(BuildError::unspanned)?;
+        }
+
+        // Check thread name
+        if let Some(thread_name) = &self.thread_name {
+            if thread_name.trim().is_empty() {
+                return Err(BuildError::unspanned("A thread name can't be empty."));
+            }
+
+            check_length(thread_name, "thread name", limits.thread_name, limits)?;
+        }
+
+        // Return success
+        Ok(())
+    }
+}
@@ -0,0 +1,236 @@
+use crate::decompilers;
+use crate::enums::Overflow;
+use crate::structs::{BuildError, BuildWarning, Limits, MessagePayload, StoredMessage};
+use serenity::all::{
+    CreateInteractionResponse, CreateInteractionResponseMessage, CreateMessage,
+    EditInteractionResponse, EditMessage, ExecuteWebhook,
+};
+
+#[derive(Debug)]
+/// The messages produced by building a VeaScript script.
+pub struct VeaScriptOutput {
+    /// The messages produced by a VeaScript script, in the order they should be sent.
+    pub messages: Vec<StoredMessage>,
+
+    /// The non-fatal problems found while building the output.
+    pub warnings: Vec<BuildWarning>,
+}
+
+impl Default for VeaScriptOutput {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl VeaScriptOutput {
+    /// Create a new output struct.
+    pub fn new() -> Self {
+        Self {
+            messages: Vec::new(),
+            warnings: Vec::new(),
+        }
+    }
+
+    /// Import a message from Discord's JSON format, as used by the API and Discohook.
+    pub fn from_discord_json(json: &str) -> Result<Self, BuildError> {
+        // Parse message
+        let payload: MessagePayload = serde_json::from_str(json).map_err(|error| {
+            BuildError::unspanned(format!("Invalid Discord message JSON: {}.", error))
+        })?;
+
+        // Convert message into output
+        let mut output = Self::new();
+        output.push_message(StoredMessage::try_from(payload)?);
+
+        // Check message limits
+        output.validate(&Limits::discord())?;
+
+        // Return output
+        Ok(output)
+    }
+
+    /// Export each message in Discord's JSON format, ready to send to the API or a webhook.
+    pub fn to_discord_json(&self) -> Result<Vec<String>, BuildError> {
+        self.messages
+            .iter()
+            .map(StoredMessage::to_discord_json)
+            .collect()
+    }
+
+    /// Decompile the output into canonical VeaScript source.
+    pub fn to_veascript(&self) -> String {
+        decompilers::output(self)
+    }
+
+    /// Add a message to the output.
+    pub fn push_message(&mut self, message: StoredMessage) {
+        self.messages.push(message);
+    }
+
+    /// Add a warning to the output.
+    pub fn push_warning(&mut self, warning: BuildWarning) {
+        self.warnings.push(warning);
+    }
+
+    /// Handle message content that is longer than the content limit, adding a warning for each
+    /// message that is changed.
+    pub fn overflow(&mut self, overflow: Overflow, limits: &Limits) {
+        self.messages = std::mem::take(&mut self.messages)
+            .into_iter()
+            .flat_map(|message| message.overflow(overflow, limits, &mut self.warnings))
+            .collect();
+    }
+
+    /// Shorten content and remove embeds until every message fits within the given limits,
+    /// adding a warning for each change.
+    pub fn fit(&mut self, limits: &Limits) {
+        for message in &mut self.messages {
+            message.fit(limits, &mut self.warnings);
+        }
+    }
+
+    /// Check that every message can be sent within the given limits.
+    pub fn validate(&self, limits: &Limits) -> Result<(), BuildError> {
+        self.messages
+            .iter()
+            .try_for_each(|message| message.validate(limits))
+    }
+
+    /// Convert the output of a handler into the response that updates the message whose
+    /// component was used, failing if the handler produced more than one message.
+    pub fn into_update_response(self) -> Result<CreateInteractionResponse, BuildError> {
+        Ok(CreateInteractionResponse::UpdateMessage(self.try_into()?))
+    }
+
+    /// Get the only message, which is the one sent when the output is converted into a single
+    /// message builder. Outputs with several messages are rejected rather than having the
+    /// rest silently dropped.
+    fn into_single_message(self) -> Result<StoredMessage, BuildError> {
+        // Check message count
+        if self.messages.len() > 1 {
+            return Err(BuildError::unspanned(format!(
+                "This output has {} messages, but only one message can be sent here.",
+                self.messages.len()
+            )));
+        }
+
+        // Return message
+        Ok(self.messages.into_iter().next().unwrap_or_default())
+    }
+}
+
+impl TryFrom<VeaScriptOutput> for CreateMessage {
+    type Error = BuildError;
+
+    fn try_from(value: VeaScriptOutput) -> Result<Self, Self::Error> {
+        value.into_single_message()?.try_into()
+    }
+}
+
+impl TryFrom<VeaScriptOutput> for EditMessage {
+    type Error = BuildError;
+
+    fn try_from(value: VeaScriptOutput) -> Result<Self, Self::Error> {
+        value.into_single_message()?.try_into()
+    }
+}
+
+impl TryFrom<VeaScriptOutput> for CreateInteractionResponseMessage {
+    type Error = BuildError;
+
+    fn try_from(value: VeaScriptOutput) -> Result<Self, Self::Error> {
+        value.into_single_message()?.try_into()
+    }
+}
+
+impl TryFrom<VeaScriptOutput> for EditInteractionResponse {
+    type Error = BuildError;
+
+    fn try_from(value: VeaScriptOutput) -> Result<Self, Self::Error> {
+        value.into_single_message()?.try_into()
+    }
+}
+
+impl TryFrom<VeaScriptOutput> for ExecuteWebhook {
+    type Error = BuildError;
+
+    fn try_from(value: VeaScriptOutput) -> Result<Self, Self::Error> {
+        value.into_single_message()?.try_into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn imports_null_lists_as_empty() {
+        let output = VeaScriptOutput::from_discord_json(
+            r#"{"content":"hi","embeds":null,"attachments":[],"components":null}"#,
+        )
+        .unwrap();
+        assert_eq!(output.messages[0].content, "hi");
+        assert!(output.messages[0].embeds.is_empty());
+        assert!(output.messages[0].components.is_empty());
+    }
+
+    #[test]
+    fn imports_integer_colours_as_hex() {
+        let output =
+            VeaScriptOutput::from_discord_json(r#"{"embeds":[{"title":"t","color":5793266}]}"#)
+                .unwrap();
+        assert_eq!(output.messages[0].embeds[0].colour, 0x5865F2);
+        assert!(output.to_veascript().contains("#colour: #5865f2,"));
+
+        // Colours above #FFFFFF are rejected
+        assert!(VeaScriptOutput::from_discord_json(
+            r#"{"embeds":[{"title":"t","color":16777216}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn imports_iso_timestamps() {
+        let output = VeaScriptOutput::from_discord_json(
+            r#"{"embeds":[{"title":"t","timestamp":"2024-01-02T03:04:05.000Z"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(output.messages[0].embeds[0].timestamp, Some(1_704_164_645));
+        assert!(output.to_veascript().contains("#timestamp: 1704164645,"));
+
+        // Timestamps that aren't dates are rejected
+        assert!(VeaScriptOutput::from_discord_json(
+            r#"{"embeds":[{"title":"t","timestamp":"not a date"}]}"#
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn rejects_unknown_keys() {
+        assert!(
+            VeaScriptOutput::from_discord_json(r#"{"content":"hi","sticker_ids":[]}"#).is_err()
+        );
+        assert!(
+            VeaScriptOutput::from_discord_json(r#"{"embeds":[{"title":"t","colour":1}]}"#).is_err()
+        );
+    }
+
+    #[test]
+    fn decompiles_imported_messages() {
+        let output = VeaScriptOutput::from_discord_json(
+            r#"{"content":"Hello {there}","embeds":[{"title":"Say \"hi\"","description":"Line one\nLine two"}]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            output.to_veascript(),
+            concat!(
+                "#text {Hello {there\\}}\n",
+                "\n",
+                "#embed {\n",
+                "    #title: \"Say \\\"hi\\\"\",\n",
+                "    #description: \"Line one\\nLine two\",\n",
+                "}\n",
+            )
+        );
+    }
+}